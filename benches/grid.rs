@@ -0,0 +1,132 @@
+//! Compare flat Grid storage with the previous row-per-allocation layout.
+//!
+//! Run with `cargo bench --bench grid`.
+
+use std::{
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use puzzler::grids::{direction::Direction, grid::Grid, point::Point};
+
+const SIZE: usize = 500;
+const ROUNDS: usize = 20;
+
+/// Replica of the previous Grid layout, one heap allocation per row
+struct NestedGrid {
+    rows: usize,
+    cols: usize,
+    internal: Vec<Vec<char>>,
+}
+
+impl NestedGrid {
+    fn new(data: Vec<Vec<char>>) -> Self {
+        Self {
+            rows: data.len(),
+            cols: data[0].len(),
+            internal: data,
+        }
+    }
+
+    fn is_point_in_grid(&self, point: &Point) -> bool {
+        point.x >= 0 && point.x < self.cols as isize && point.y >= 0 && point.y < self.rows as isize
+    }
+
+    fn get(&self, point: &Point) -> char {
+        self.internal[point.y as usize][point.x as usize]
+    }
+
+    fn neighbors(&self, point: &Point, directions: &[Direction]) -> Vec<(Point, Direction)> {
+        directions
+            .iter()
+            .filter_map(|direction| {
+                let neighbor = point.neighbor(direction);
+                match self.is_point_in_grid(&neighbor) {
+                    true => Some((neighbor, *direction)),
+                    false => None,
+                }
+            })
+            .collect()
+    }
+}
+
+fn build_data() -> Vec<Vec<char>> {
+    (0..SIZE)
+        .map(|y| {
+            (0..SIZE)
+                .map(|x| if (x * 7 + y * 13) % 5 == 0 { '#' } else { '.' })
+                .collect()
+        })
+        .collect()
+}
+
+fn measure<F: FnMut() -> usize>(name: &str, mut func: F) -> Duration {
+    // Warm up caches before measuring
+    black_box(func());
+
+    let start = Instant::now();
+    for _ in 0..ROUNDS {
+        black_box(func());
+    }
+    let elapsed = start.elapsed() / ROUNDS as u32;
+
+    println!("{name:<32} {elapsed:>12.3?}");
+    elapsed
+}
+
+fn main() {
+    let data = build_data();
+    let nested = NestedGrid::new(data.clone());
+    let flat = Grid::new(data).unwrap();
+
+    let points = (0..SIZE * SIZE)
+        .map(|i| Point::new((i % SIZE) as isize, (i / SIZE) as isize))
+        .collect::<Vec<_>>();
+
+    println!("Grid {SIZE}x{SIZE}, average of {ROUNDS} rounds");
+
+    let before = measure("neighbors (nested)", || {
+        points
+            .iter()
+            .flat_map(|p| nested.neighbors(p, &Direction::CARDINAL))
+            .filter(|(n, _)| nested.get(n) == '#')
+            .count()
+    });
+    let after = measure("neighbors (flat)", || {
+        points
+            .iter()
+            .flat_map(|p| flat.neighbors(p, &Direction::CARDINAL))
+            .filter(|(n, _)| flat[*n] == '#')
+            .count()
+    });
+    println!(
+        "{:<32} {:>11.2}x",
+        "speedup",
+        before.as_secs_f64() / after.as_secs_f64()
+    );
+
+    let before = measure("scan (nested)", || {
+        points.iter().filter(|p| nested.get(p) == '#').count()
+    });
+    let after = measure("scan (flat)", || {
+        points.iter().filter(|p| flat[**p] == '#').count()
+    });
+    println!(
+        "{:<32} {:>11.2}x",
+        "speedup",
+        before.as_secs_f64() / after.as_secs_f64()
+    );
+
+    measure("scan (flat, usize index)", || {
+        (0..flat.rows() * flat.cols())
+            .filter(|i| flat[*i] == '#')
+            .count()
+    });
+    measure("scan (flat, unchecked)", || {
+        (0..flat.rows() * flat.cols())
+            // SAFETY: Index is always lower than rows * cols
+            .filter(|i| unsafe { *flat.get_unchecked(*i) } == '#')
+            .count()
+    });
+    measure("get_value (flat)", || flat.get_value('#').len());
+}
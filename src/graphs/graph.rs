@@ -0,0 +1,147 @@
+use std::ops::Range;
+
+use crate::symbols::interner::Interner;
+
+/// Graph with named nodes. Names are interned to dense ids (0..len), so that algorithms can use
+/// plain vectors indexed by node id. Every node can carry user data, e.g. valve flow rate.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Graph<T = ()> {
+    interner: Interner,
+    data: Vec<T>,
+    edges: Vec<Vec<usize>>,
+}
+
+impl<T: Default> Graph<T> {
+    pub fn new() -> Self {
+        Self {
+            interner: Interner::new(),
+            data: Vec::new(),
+            edges: Vec::new(),
+        }
+    }
+
+    /// Add node if it does not exist yet, id of the node is returned
+    pub fn add_node(&mut self, name: &str) -> usize {
+        let id = self.interner.intern(name) as usize;
+
+        if id == self.data.len() {
+            self.data.push(T::default());
+            self.edges.push(Vec::new());
+        }
+        id
+    }
+
+    /// Add directed edge, missing nodes are added
+    pub fn add_edge(&mut self, from: &str, to: &str) -> (usize, usize) {
+        let from = self.add_node(from);
+        let to = self.add_node(to);
+        self.add_edge_by_id(from, to);
+        (from, to)
+    }
+
+    /// Add edge in both directions, missing nodes are added
+    pub fn add_undirected_edge(&mut self, a: &str, b: &str) -> (usize, usize) {
+        let (a, b) = self.add_edge(a, b);
+        self.add_edge_by_id(b, a);
+        (a, b)
+    }
+}
+
+impl<T> Graph<T> {
+    /// Number of nodes
+    pub fn len(&self) -> usize {
+        self.interner.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.interner.is_empty()
+    }
+
+    /// Ids of all nodes
+    pub fn nodes(&self) -> Range<usize> {
+        0..self.interner.len()
+    }
+
+    pub fn id(&self, name: &str) -> Option<usize> {
+        self.interner.get(name).map(|id| id as usize)
+    }
+
+    pub fn name(&self, id: usize) -> &str {
+        &self.interner.names()[id]
+    }
+
+    pub fn data(&self, id: usize) -> &T {
+        &self.data[id]
+    }
+
+    pub fn data_mut(&mut self, id: usize) -> &mut T {
+        &mut self.data[id]
+    }
+
+    pub fn neighbors(&self, id: usize) -> &[usize] {
+        &self.edges[id]
+    }
+
+    /// Add directed edge between existing nodes, duplicate edges are ignored
+    pub fn add_edge_by_id(&mut self, from: usize, to: usize) {
+        if !self.edges[from].contains(&to) {
+            self.edges[from].push(to);
+        }
+    }
+
+    /// Number of directed edges
+    pub fn edge_count(&self) -> usize {
+        self.edges.iter().map(Vec::len).sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_add_node() {
+        let mut graph = Graph::<()>::new();
+
+        assert_eq!(graph.add_node("a"), 0);
+        assert_eq!(graph.add_node("b"), 1);
+        assert_eq!(graph.add_node("a"), 0);
+        assert_eq!(graph.len(), 2);
+        assert_eq!(graph.id("b"), Some(1));
+        assert_eq!(graph.id("c"), None);
+        assert_eq!(graph.name(1), "b");
+    }
+
+    #[test]
+    fn test_add_edge() {
+        let mut graph = Graph::<()>::new();
+
+        assert_eq!(graph.add_edge("a", "b"), (0, 1));
+        assert_eq!(graph.add_edge("a", "b"), (0, 1));
+        assert_eq!(graph.add_edge("a", "c"), (0, 2));
+
+        assert_eq!(graph.neighbors(0), &[1, 2]);
+        assert!(graph.neighbors(1).is_empty());
+        assert_eq!(graph.edge_count(), 2);
+    }
+
+    #[test]
+    fn test_add_undirected_edge() {
+        let mut graph = Graph::<()>::new();
+
+        graph.add_undirected_edge("a", "b");
+
+        assert_eq!(graph.neighbors(0), &[1]);
+        assert_eq!(graph.neighbors(1), &[0]);
+    }
+
+    #[test]
+    fn test_data() {
+        let mut graph = Graph::<usize>::new();
+
+        let id = graph.add_node("AA");
+        *graph.data_mut(id) = 42;
+
+        assert_eq!(*graph.data(id), 42);
+    }
+}
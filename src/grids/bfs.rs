@@ -0,0 +1,302 @@
+use std::collections::{HashSet, VecDeque};
+
+use super::{direction::Direction, grid::Grid, point::Point};
+
+/// Result of the breadth-first search on the grid
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bfs {
+    distances: Grid<Option<usize>>,
+    predecessors: Grid<Option<Point>>,
+    reached: Vec<Point>,
+}
+
+impl Bfs {
+    /// Number of steps from the nearest start point, None if point was not reached
+    pub fn distance(&self, point: &Point) -> Option<usize> {
+        self.distances.get(point).copied().flatten()
+    }
+
+    pub fn distances(&self) -> &Grid<Option<usize>> {
+        &self.distances
+    }
+
+    /// Previous point on the shortest path, None for start points and unreached points
+    pub fn predecessor(&self, point: &Point) -> Option<Point> {
+        self.predecessors.get(point).copied().flatten()
+    }
+
+    pub fn predecessors(&self) -> &Grid<Option<Point>> {
+        &self.predecessors
+    }
+
+    /// Targets reached before the search stopped, in order of their distance
+    pub fn reached(&self) -> &[Point] {
+        &self.reached
+    }
+
+    /// Shortest path from the nearest start point to the target, both ends are included
+    pub fn path_to(&self, target: &Point) -> Option<Vec<Point>> {
+        self.distance(target)?;
+
+        let mut path = vec![*target];
+        while let Some(previous) = self.predecessor(&path[path.len() - 1]) {
+            path.push(previous);
+        }
+        path.reverse();
+
+        Some(path)
+    }
+
+    fn visit(&mut self, point: Point, distance: usize, from: Option<Point>, is_target: bool) {
+        self.distances[point] = Some(distance);
+        self.predecessors[point] = from;
+
+        if is_target {
+            self.reached.push(point);
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    /// Compute distances from all start points to every reachable point. Step from one point to
+    /// its neighbor in one of the directions is possible only if passable(from, to) is true.
+    pub fn bfs<F>(&self, starts: &[Point], directions: &[Direction], passable: F) -> Bfs
+    where
+        F: Fn(&Point, &Point) -> bool,
+    {
+        self.bfs_until(starts, directions, passable, |_| false)
+    }
+
+    /// Same as bfs(), but the search stops as soon as the first point matching is_target is reached
+    pub fn bfs_until<F, G>(
+        &self,
+        starts: &[Point],
+        directions: &[Direction],
+        passable: F,
+        is_target: G,
+    ) -> Bfs
+    where
+        F: Fn(&Point, &Point) -> bool,
+        G: Fn(&Point) -> bool,
+    {
+        self.search(starts, directions, passable, is_target, 1)
+    }
+
+    /// Same as bfs(), but the search stops as soon as all targets are reached. Targets outside of
+    /// the grid are ignored, if there is no target within the grid the whole grid is searched.
+    pub fn bfs_targets<F>(
+        &self,
+        starts: &[Point],
+        directions: &[Direction],
+        passable: F,
+        targets: &[Point],
+    ) -> Bfs
+    where
+        F: Fn(&Point, &Point) -> bool,
+    {
+        let unique = targets
+            .iter()
+            .filter(|target| self.is_point_in_grid(target))
+            .collect::<HashSet<_>>();
+
+        if unique.is_empty() {
+            return self.bfs(starts, directions, passable);
+        }
+
+        self.search(
+            starts,
+            directions,
+            passable,
+            |point| unique.contains(point),
+            unique.len(),
+        )
+    }
+
+    // Search the grid until 'count' points matching is_target are reached
+    fn search<F, G>(
+        &self,
+        starts: &[Point],
+        directions: &[Direction],
+        passable: F,
+        is_target: G,
+        count: usize,
+    ) -> Bfs
+    where
+        F: Fn(&Point, &Point) -> bool,
+        G: Fn(&Point) -> bool,
+    {
+        let mut result = Bfs {
+            distances: self.map(|_| None),
+            predecessors: self.map(|_| None),
+            reached: Vec::new(),
+        };
+        let mut queue = VecDeque::new();
+
+        // Distance of the point is final once it is discovered, so targets are checked right away
+        for start in starts {
+            if !self.is_point_in_grid(start) || result.distances[*start].is_some() {
+                continue;
+            }
+
+            result.visit(*start, 0, None, is_target(start));
+            if result.reached.len() == count {
+                return result;
+            }
+            queue.push_back(*start);
+        }
+
+        while let Some(point) = queue.pop_front() {
+            let distance = result.distances[point].unwrap_or_default();
+
+            for direction in directions {
+                let neighbor = point.neighbor(direction);
+
+                if !self.is_point_in_grid(&neighbor)
+                    || result.distances[neighbor].is_some()
+                    || !passable(&point, &neighbor)
+                {
+                    continue;
+                }
+
+                result.visit(neighbor, distance + 1, Some(point), is_target(&neighbor));
+                if result.reached.len() == count {
+                    return result;
+                }
+                queue.push_back(neighbor);
+            }
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parsers::parser::Parser;
+
+    use super::*;
+
+    fn build_maze() -> Grid {
+        let lines = ["S.#....", "..#.##.", "....#E.", "###.#..", "....#.#"];
+
+        Parser::parse_lines_to_grid_str(&lines).unwrap()
+    }
+
+    fn open(grid: &Grid) -> impl Fn(&Point, &Point) -> bool {
+        |_, to| grid[*to] != '#'
+    }
+
+    #[test]
+    fn test_bfs_distances() {
+        let grid = build_maze();
+        let bfs = grid.bfs(&[Point::new(0, 0)], &Direction::CARDINAL, open(&grid));
+
+        assert_eq!(bfs.distance(&Point::new(0, 0)), Some(0));
+        assert_eq!(bfs.distance(&Point::new(1, 1)), Some(2));
+        assert_eq!(bfs.distance(&Point::new(5, 2)), Some(13));
+        assert_eq!(bfs.distance(&Point::new(0, 4)), Some(10));
+        assert_eq!(bfs.distance(&Point::new(2, 0)), None);
+        assert_eq!(bfs.distance(&Point::new(-1, 0)), None);
+        assert!(bfs.reached().is_empty());
+    }
+
+    #[test]
+    fn test_bfs_path_to() {
+        let grid = build_maze();
+        let bfs = grid.bfs(&[Point::new(0, 0)], &Direction::CARDINAL, open(&grid));
+
+        let path = bfs.path_to(&Point::new(3, 3)).unwrap();
+        assert_eq!(path.len(), 7);
+        assert_eq!(path[0], Point::new(0, 0));
+        assert_eq!(path[6], Point::new(3, 3));
+        assert!(
+            path.windows(2)
+                .all(|pair| (pair[0].x - pair[1].x).abs() + (pair[0].y - pair[1].y).abs() == 1)
+        );
+
+        assert_eq!(bfs.path_to(&Point::new(0, 0)), Some(vec![Point::new(0, 0)]));
+        assert_eq!(bfs.path_to(&Point::new(2, 0)), None);
+        assert_eq!(bfs.predecessor(&Point::new(0, 0)), None);
+    }
+
+    #[test]
+    fn test_bfs_multiple_starts() {
+        let grid = build_maze();
+        let starts = [Point::new(0, 0), Point::new(5, 2)];
+        let bfs = grid.bfs(&starts, &Direction::CARDINAL, open(&grid));
+
+        assert_eq!(bfs.distance(&Point::new(5, 2)), Some(0));
+        assert_eq!(bfs.distance(&Point::new(6, 0)), Some(3));
+        assert_eq!(bfs.distance(&Point::new(3, 0)), Some(6));
+    }
+
+    #[test]
+    fn test_bfs_all_directions() {
+        let grid = build_maze();
+        let bfs = grid.bfs(&[Point::new(0, 0)], &Direction::ALL, open(&grid));
+
+        assert_eq!(bfs.distance(&Point::new(3, 3)), Some(3));
+        assert_eq!(bfs.distance(&Point::new(5, 2)), Some(7));
+    }
+
+    #[test]
+    fn test_bfs_until() {
+        let grid = build_maze();
+        let bfs = grid.bfs_until(
+            &[Point::new(0, 0)],
+            &Direction::CARDINAL,
+            open(&grid),
+            |p| grid[*p] == 'E',
+        );
+
+        assert_eq!(bfs.reached(), &[Point::new(5, 2)]);
+        assert_eq!(bfs.distance(&Point::new(5, 2)), Some(13));
+        assert_eq!(bfs.path_to(&Point::new(5, 2)).unwrap().len(), 14);
+
+        // Points further than the target are not explored
+        assert_eq!(bfs.distance(&Point::new(5, 4)), None);
+    }
+
+    #[test]
+    fn test_bfs_targets() {
+        let grid = build_maze();
+        let targets = [Point::new(3, 4), Point::new(1, 0), Point::new(3, 4)];
+        let bfs = grid.bfs_targets(
+            &[Point::new(0, 0)],
+            &Direction::CARDINAL,
+            open(&grid),
+            &targets,
+        );
+
+        assert_eq!(bfs.reached(), &[Point::new(1, 0), Point::new(3, 4)]);
+        assert_eq!(bfs.distance(&Point::new(3, 4)), Some(7));
+        assert_eq!(bfs.distance(&Point::new(6, 0)), None);
+    }
+
+    #[test]
+    fn test_bfs_targets_outside_grid() {
+        let grid = build_maze();
+        let starts = [Point::new(0, 0), Point::new(6, 0)];
+        let full = grid.bfs(&starts, &Direction::CARDINAL, open(&grid));
+
+        for targets in [vec![], vec![Point::new(7, 0), Point::new(0, -1)]] {
+            let bfs = grid.bfs_targets(&starts, &Direction::CARDINAL, open(&grid), &targets);
+
+            assert_eq!(bfs.distance(&Point::new(6, 0)), Some(0));
+            assert_eq!(bfs.distance(&Point::new(0, 4)), Some(10));
+            assert_eq!(bfs, full);
+        }
+    }
+
+    #[test]
+    fn test_bfs_passable_by_step() {
+        // Step is possible only to cells at most one higher
+        let grid = Grid::new(vec![vec![0, 1, 3], vec![1, 2, 3]]).unwrap();
+        let bfs = grid.bfs(&[Point::new(0, 0)], &Direction::CARDINAL, |from, to| {
+            grid[*to] <= grid[*from] + 1
+        });
+
+        assert_eq!(bfs.distance(&Point::new(2, 1)), Some(3));
+        assert_eq!(bfs.predecessor(&Point::new(2, 0)), Some(Point::new(2, 1)));
+    }
+}
@@ -1,20 +1,22 @@
 use std::{
+    collections::HashSet,
     error::Error,
     ops::{Index, IndexMut},
 };
 
-use super::{direction::Direction, point::Point};
+use super::{direction::Direction, point::Point, render::Renderer};
 
-#[derive(Default, Clone, PartialEq, Eq)]
-pub struct Grid {
+/// Two dimensional grid of cells, cells are characters unless specified otherwise
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T = char> {
     rows: usize,
     cols: usize,
-    internal: Vec<Vec<char>>,
+    internal: Vec<T>,
 }
 
-impl Grid {
+impl<T> Grid<T> {
     /// Create new grid, rows and columns count is deduced from input data
-    pub fn new(data: Vec<Vec<char>>) -> Result<Self, Box<dyn Error>> {
+    pub fn new(data: Vec<Vec<T>>) -> Result<Self, Box<dyn Error>> {
         // Check input data
         if data.is_empty() {
             return Err("Grid is empty".into());
@@ -27,29 +29,28 @@ impl Grid {
         let rows = data.len();
         let cols = data[0].len();
 
+        // Rows are stored one after another, all of them must have the same length
+        if let Some((i, row)) = data.iter().enumerate().find(|(_, row)| row.len() != cols) {
+            return Err(format!("Grid[{i}] has {} columns, expected {cols}", row.len()).into());
+        }
+
         Ok(Self {
             rows,
             cols,
-            internal: data,
+            internal: data.into_iter().flatten().collect(),
         })
     }
 
     pub fn new_with<F>(rows: usize, cols: usize, func: F) -> Result<Self, Box<dyn Error>>
     where
-        F: Fn(Point) -> char,
+        F: Fn(Point) -> T,
     {
-        let internal = (0..rows)
-            .map(|i| {
-                {
-                    (0..cols)
-                        .map(|j| {
-                            func(Point {
-                                x: j as isize,
-                                y: i as isize,
-                            })
-                        })
-                        .collect::<Vec<_>>()
-                }
+        let internal = (0..rows * cols)
+            .map(|index| {
+                func(Point {
+                    x: (index % cols) as isize,
+                    y: (index / cols) as isize,
+                })
             })
             .collect::<Vec<_>>();
 
@@ -68,23 +69,55 @@ impl Grid {
         self.cols
     }
 
-    pub fn fill(&mut self, data: &[(Point, char)]) -> Result<(), Box<dyn Error>> {
-        // Avoid changing of the grid if there is invalid point
-        for (point, _) in data {
-            if !self.is_point_in_grid(point) {
-                return Err(format!("Point {point:?} is not in the grid").into());
-            }
+    pub fn is_point_in_grid(&self, point: &Point) -> bool {
+        point.x >= 0 && point.x < self.cols as isize && point.y >= 0 && point.y < self.rows as isize
+    }
+
+    /// Index of the point in row-major storage, point must be within the grid
+    #[inline]
+    pub fn index_of(&self, point: &Point) -> usize {
+        point.y as usize * self.cols + point.x as usize
+    }
+
+    /// Point of the index in row-major storage
+    #[inline]
+    pub fn point_of(&self, index: usize) -> Point {
+        Point {
+            x: (index % self.cols) as isize,
+            y: (index / self.cols) as isize,
         }
+    }
 
-        for (point, value) in data {
-            self[*point] = *value;
+    /// Get value at the point, None if point is outside of the grid
+    #[inline]
+    pub fn get(&self, point: &Point) -> Option<&T> {
+        match self.is_point_in_grid(point) {
+            true => Some(&self.internal[self.index_of(point)]),
+            false => None,
         }
+    }
 
-        Ok(())
+    /// Get mutable value at the point, None if point is outside of the grid
+    #[inline]
+    pub fn get_mut(&mut self, point: &Point) -> Option<&mut T> {
+        match self.is_point_in_grid(point) {
+            true => {
+                let index = self.index_of(point);
+                Some(&mut self.internal[index])
+            }
+            false => None,
+        }
     }
 
-    pub fn is_point_in_grid(&self, point: &Point) -> bool {
-        point.x >= 0 && point.x < self.cols as isize && point.y >= 0 && point.y < self.rows as isize
+    /// Get value at the row-major index without bounds checking
+    ///
+    /// # Safety
+    ///
+    /// Index must be lower than rows() * cols()
+    #[inline]
+    pub unsafe fn get_unchecked(&self, index: usize) -> &T {
+        // SAFETY: Guaranteed by the caller
+        unsafe { self.internal.get_unchecked(index) }
     }
 
     pub fn neighbor(&self, point: &Point, direction: &Direction) -> Option<(Point, Direction)> {
@@ -132,267 +165,341 @@ impl Grid {
             .collect::<Vec<_>>()
     }
 
-    pub fn get_if<F>(&self, func: F) -> Vec<Point>
-    where
-        F: Copy + Fn(char) -> bool,
-    {
-        self.internal
-            .iter()
-            .enumerate()
-            .flat_map(|(i, row)| {
-                row.iter()
-                    .enumerate()
-                    .filter_map(move |(j, c)| match func(*c) {
-                        true => Some(Point {
-                            x: j as isize,
-                            y: i as isize,
-                        }),
-                        false => None,
-                    })
-            })
-            .collect()
+    /// Map any point to the grid as if the grid was a torus, i.e. coordinates are taken modulo
+    /// cols and rows. Moving by k cells east matches row_rotate_right() by k.
+    pub fn wrap_point(&self, point: &Point) -> Point {
+        Point {
+            x: point.x.rem_euclid(self.cols as isize),
+            y: point.y.rem_euclid(self.rows as isize),
+        }
     }
 
-    /// Get positions of all values from the grid
-    pub fn get_value(&self, value: char) -> Vec<Point> {
-        self.get_value_if(value, || true)
+    /// Same as neighbor(), but neighbors outside of the grid wrap around the edges
+    pub fn neighbor_wrapped(&self, point: &Point, direction: &Direction) -> (Point, Direction) {
+        (self.wrap_point(&point.neighbor(direction)), *direction)
     }
 
-    pub fn get_value_if<F>(&self, value: char, func: F) -> Vec<Point>
+    pub fn neighbor_wrapped_if<F>(
+        &self,
+        point: &Point,
+        direction: &Direction,
+        func: F,
+    ) -> Option<(Point, Direction)>
     where
-        F: Copy + Fn() -> bool,
+        F: Fn(&Point, &Direction) -> bool,
     {
-        self.internal
-            .iter()
-            .enumerate()
-            .flat_map(|(i, row)| {
-                row.iter()
-                    .enumerate()
-                    .filter_map(move |(j, c)| match *c == value && func() {
-                        true => Some(Point {
-                            x: j as isize,
-                            y: i as isize,
-                        }),
-                        false => None,
-                    })
-            })
-            .collect()
-    }
+        let (neighbor, direction) = self.neighbor_wrapped(point, direction);
 
-    /// Print grid to the console.
-    pub fn print(&self) {
-        self.print_with_visited(&[]);
+        match func(&neighbor, &direction) {
+            true => Some((neighbor, direction)),
+            false => None,
+        }
     }
 
-    /// Print grid to the console. If point is in visited collection show 'O' for this point.
-    pub fn print_with_visited(&self, visited: &[Point]) {
-        // Go through all rows
-        for i in 0..self.rows {
-            // Format whole line and print it only once to the console to speedup writesS
-            let line = self.internal[i]
-                .iter()
-                .enumerate()
-                .map(|(j, c)| {
-                    match visited.contains(&Point {
-                        x: j as isize,
-                        y: i as isize,
-                    }) {
-                        true => 'O',
-                        false => *c,
-                    }
-                })
-                .collect::<String>();
+    pub fn neighbors_wrapped(
+        &self,
+        point: &Point,
+        directions: &[Direction],
+    ) -> Vec<(Point, Direction)> {
+        directions
+            .iter()
+            .map(|direction| self.neighbor_wrapped(point, direction))
+            .collect::<Vec<_>>()
+    }
 
-            println!("{line}");
-        }
+    pub fn neighbors_wrapped_if<F>(
+        &self,
+        point: &Point,
+        directions: &[Direction],
+        func: F,
+    ) -> Vec<(Point, Direction)>
+    where
+        F: Fn(&Point, &Direction) -> bool,
+    {
+        directions
+            .iter()
+            .filter_map(|direction| self.neighbor_wrapped_if(point, direction, &func))
+            .collect::<Vec<_>>()
     }
 
-    // Compare grid with &str per line. Used in unittests.
-    pub fn equals(&self, raw: &[&str]) -> bool {
-        // Check number of rows
-        if self.rows != raw.len() {
-            return false;
+    /// Create new grid of the same size, every cell is converted with the function
+    pub fn map<U, F>(&self, func: F) -> Grid<U>
+    where
+        F: Fn(&T) -> U,
+    {
+        Grid {
+            rows: self.rows,
+            cols: self.cols,
+            internal: self.internal.iter().map(func).collect(),
         }
+    }
 
-        // For every row check every column
-        for (row_index, row) in raw.iter().enumerate() {
-            // Save current row into bytes
-            let bytes = row.as_bytes();
+    // Cells of the row
+    fn row(&self, row: usize) -> &[T] {
+        &self.internal[row * self.cols..(row + 1) * self.cols]
+    }
 
-            // Check number of columns
-            if self.cols != bytes.len() {
-                return false;
-            }
+    fn row_mut(&mut self, row: usize) -> &mut [T] {
+        &mut self.internal[row * self.cols..(row + 1) * self.cols]
+    }
+}
 
-            // Compare all columns
-            for (col_index, col) in bytes.iter().enumerate() {
-                if self.internal[row_index][col_index] != *col as char {
-                    return false;
-                }
+impl<T: Clone> Grid<T> {
+    pub fn fill(&mut self, data: &[(Point, T)]) -> Result<(), Box<dyn Error>> {
+        // Avoid changing of the grid if there is invalid point
+        for (point, _) in data {
+            if !self.is_point_in_grid(point) {
+                return Err(format!("Point {point:?} is not in the grid").into());
             }
         }
 
-        true
-    }
-
-    pub fn row_as_string(&self, row: usize) -> Option<String> {
-        if row >= self.rows {
-            return None;
+        for (point, value) in data {
+            self[*point] = value.clone();
         }
 
-        Some(self.internal[row].iter().collect::<String>())
+        Ok(())
     }
 
-    pub fn col_as_string(&self, col: usize) -> Option<String> {
-        if col >= self.cols {
-            return None;
-        }
-
-        let mut column = String::with_capacity(self.rows);
-        for row in 0..self.rows {
-            column.push(self.internal[row][col]);
-        }
-        Some(column)
+    pub fn get_if<F>(&self, func: F) -> Vec<Point>
+    where
+        F: Copy + Fn(T) -> bool,
+    {
+        self.internal
+            .iter()
+            .enumerate()
+            .filter_map(|(index, c)| match func(c.clone()) {
+                true => Some(self.point_of(index)),
+                false => None,
+            })
+            .collect()
     }
 
-    pub fn row_shift_left(&mut self, row: usize, shuffle: usize, default: char) {
+    pub fn row_shift_left(&mut self, row: usize, shuffle: usize, default: T) {
         if shuffle == 0 {
             return;
         }
 
         for i in 0..self.cols {
             if i < self.cols.saturating_sub(shuffle) {
-                self.internal[row].swap(i, i + shuffle);
+                self.row_mut(row).swap(i, i + shuffle);
             } else {
-                self.internal[row][i] = default;
+                self.row_mut(row)[i] = default.clone();
             }
         }
     }
 
-    pub fn row_shift_right(&mut self, row: usize, shuffle: usize, default: char) {
+    pub fn row_shift_right(&mut self, row: usize, shuffle: usize, default: T) {
         if shuffle == 0 {
             return;
         }
 
         for i in (0..self.cols).rev() {
             if i + shuffle < self.cols {
-                self.internal[row].swap(i, i + shuffle);
+                self.row_mut(row).swap(i, i + shuffle);
             } else {
-                self.internal[row][i] = default;
+                self.row_mut(row)[i] = default.clone();
             }
         }
     }
 
-    pub fn col_shift_down(&mut self, col: usize, shuffle: usize, default: char) {
+    pub fn col_shift_down(&mut self, col: usize, shuffle: usize, default: T) {
         if shuffle == 0 {
             return;
         }
 
         for i in (0..self.rows).rev() {
             if i < self.rows.saturating_sub(shuffle) {
-                let tmp = self.internal[i][col];
-                self.internal[i][col] = self.internal[i + shuffle][col];
-                self.internal[i + shuffle][col] = tmp;
+                self.internal
+                    .swap(i * self.cols + col, (i + shuffle) * self.cols + col);
             } else {
-                self.internal[i][col] = default;
+                self.internal[i * self.cols + col] = default.clone();
             }
         }
     }
 
-    pub fn col_shift_up(&mut self, col: usize, shuffle: usize, default: char) {
+    pub fn col_shift_up(&mut self, col: usize, shuffle: usize, default: T) {
         if shuffle == 0 {
             return;
         }
 
         for i in 0..self.rows {
             if i < self.rows.saturating_sub(shuffle) {
-                let tmp = self.internal[i][col];
-                self.internal[i][col] = self.internal[i + shuffle][col];
-                self.internal[i + shuffle][col] = tmp;
+                self.internal
+                    .swap(i * self.cols + col, (i + shuffle) * self.cols + col);
             } else {
-                self.internal[i][col] = default;
+                self.internal[i * self.cols + col] = default.clone();
             }
         }
     }
 
     pub fn row_rotate_left(&mut self, row: usize, shuffle: usize) {
-        if shuffle % self.cols == 0 {
+        if shuffle.is_multiple_of(self.cols) {
             return;
         }
 
-        let mut rotated = vec![' '; self.cols];
+        let cols = self.cols;
+        self.row_mut(row).rotate_left(shuffle % cols);
+    }
 
-        for col in 0..self.cols {
-            let orig_index = (col + shuffle) % self.cols;
-            rotated[col] = self.internal[row][orig_index];
+    pub fn row_rotate_right(&mut self, row: usize, shuffle: usize) {
+        if shuffle.is_multiple_of(self.cols) {
+            return;
         }
 
-        // Overwrite original
-        self.internal[row][..].copy_from_slice(&rotated[..]);
+        let cols = self.cols;
+        self.row_mut(row).rotate_right(shuffle % cols);
     }
 
-    pub fn row_rotate_right(&mut self, row: usize, shuffle: usize) {
-        if shuffle % self.cols == 0 {
+    pub fn col_rotate_down(&mut self, col: usize, shuffle: usize) {
+        if shuffle.is_multiple_of(self.rows) {
             return;
         }
 
-        let mut rotated = vec![' '; self.cols];
+        let mut rotated = self.col(col);
+        rotated.rotate_right(shuffle % self.rows);
 
-        for col in 0..self.cols {
-            let orig_index = (col + shuffle) % self.cols;
-            rotated[orig_index] = self.internal[row][col];
+        for (row, value) in rotated.into_iter().enumerate() {
+            self.internal[row * self.cols + col] = value;
         }
-
-        // Overwrite original
-        self.internal[row][..].copy_from_slice(&rotated[..]);
     }
 
-    pub fn col_rotate_down(&mut self, col: usize, shuffle: usize) {
-        if shuffle % self.rows == 0 {
+    pub fn col_rotate_up(&mut self, col: usize, shuffle: usize) {
+        if shuffle.is_multiple_of(self.rows) {
             return;
         }
 
-        let mut rotated = vec![' '; self.rows];
+        let mut rotated = self.col(col);
+        rotated.rotate_left(shuffle % self.rows);
 
-        for row in 0..self.rows {
-            let new_index = (row + shuffle) % self.rows;
-            rotated[new_index] = self.internal[row][col];
+        for (row, value) in rotated.into_iter().enumerate() {
+            self.internal[row * self.cols + col] = value;
         }
+    }
 
-        for row in 0..self.rows {
-            self.internal[row][col] = rotated[row];
+    // Copy values of the column
+    fn col(&self, col: usize) -> Vec<T> {
+        self.internal
+            .iter()
+            .skip(col)
+            .step_by(self.cols)
+            .cloned()
+            .collect()
+    }
+}
+
+impl<T: Clone + PartialEq> Grid<T> {
+    /// Get positions of all values from the grid
+    pub fn get_value(&self, value: T) -> Vec<Point> {
+        self.get_value_if(value, || true)
+    }
+
+    pub fn get_value_if<F>(&self, value: T, func: F) -> Vec<Point>
+    where
+        F: Copy + Fn() -> bool,
+    {
+        self.internal
+            .iter()
+            .enumerate()
+            .filter_map(|(index, c)| match *c == value && func() {
+                true => Some(self.point_of(index)),
+                false => None,
+            })
+            .collect()
+    }
+}
+
+impl Grid<char> {
+    /// Print grid to the console.
+    pub fn print(&self) {
+        Renderer::new(self).print();
+    }
+
+    /// Print grid to the console. If point is in visited collection show 'O' for this point.
+    pub fn print_with_visited(&self, visited: &[Point]) {
+        let visited = visited.iter().copied().collect::<HashSet<_>>();
+        Renderer::new(self).layer(&visited, 'O').print();
+    }
+
+    // Compare grid with &str per line. Used in unittests.
+    pub fn equals(&self, raw: &[&str]) -> bool {
+        // Check number of rows
+        if self.rows != raw.len() {
+            return false;
+        }
+
+        // For every row check every column
+        for (row_index, row) in raw.iter().enumerate() {
+            // Save current row into bytes
+            let bytes = row.as_bytes();
+
+            // Check number of columns
+            if self.cols != bytes.len() {
+                return false;
+            }
+
+            // Compare all columns
+            for (col_index, col) in bytes.iter().enumerate() {
+                if self.internal[row_index * self.cols + col_index] != *col as char {
+                    return false;
+                }
+            }
         }
+
+        true
     }
 
-    pub fn col_rotate_up(&mut self, col: usize, shuffle: usize) {
-        if shuffle % self.rows == 0 {
-            return;
+    pub fn row_as_string(&self, row: usize) -> Option<String> {
+        if row >= self.rows {
+            return None;
         }
 
-        let mut rotated = vec![' '; self.rows];
+        Some(self.row(row).iter().collect::<String>())
+    }
 
-        for row in 0..self.rows {
-            let orig_index = (row + shuffle) % self.rows;
-            rotated[row] = self.internal[orig_index][col];
+    pub fn col_as_string(&self, col: usize) -> Option<String> {
+        if col >= self.cols {
+            return None;
         }
 
+        let mut column = String::with_capacity(self.rows);
         for row in 0..self.rows {
-            self.internal[row][col] = rotated[row];
+            column.push(self.internal[row * self.cols + col]);
         }
+        Some(column)
     }
 }
 
-impl Index<Point> for Grid {
-    type Output = char;
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
 
     fn index(&self, index: Point) -> &Self::Output {
-        &self.internal[index.y as usize][index.x as usize]
+        let (rows, cols) = (self.rows, self.cols);
+
+        self.get(&index)
+            .unwrap_or_else(|| panic!("Point {index:?} is outside of the grid {rows}x{cols}"))
     }
 }
 
-impl IndexMut<Point> for Grid {
+impl<T> IndexMut<Point> for Grid<T> {
     fn index_mut(&mut self, index: Point) -> &mut Self::Output {
-        &mut self.internal[index.y as usize][index.x as usize]
+        let (rows, cols) = (self.rows, self.cols);
+
+        self.get_mut(&index)
+            .unwrap_or_else(|| panic!("Point {index:?} is outside of the grid {rows}x{cols}"))
+    }
+}
+
+impl<T> Index<usize> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.internal[index]
+    }
+}
+
+impl<T> IndexMut<usize> for Grid<T> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.internal[index]
     }
 }
 
@@ -440,6 +547,53 @@ mod tests {
         assert_eq!(grid[Point::new(0, 0)], 'x');
     }
 
+    #[test]
+    #[should_panic(expected = "outside of the grid 2x3")]
+    fn test_index_outside_cols() {
+        let grid = build_grid();
+        let _ = grid[Point::new(grid.cols() as isize, 0)];
+    }
+
+    #[test]
+    #[should_panic(expected = "outside of the grid 2x3")]
+    fn test_index_mut_negative() {
+        let mut grid = build_grid();
+        grid[Point::new(-1, 1)] = 'x';
+    }
+
+    #[test]
+    fn test_new_different_row_lengths() {
+        let result = Grid::new(vec![vec!['a', 'b'], vec!['c']]);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_index_usize() {
+        let mut grid = build_grid();
+
+        assert_eq!(grid.index_of(&Point::new(1, 1)), 4);
+        assert_eq!(grid.point_of(4), Point::new(1, 1));
+        assert_eq!(grid[4], 'e');
+
+        grid[5] = 'x';
+        assert_eq!(grid[Point::new(2, 1)], 'x');
+        assert_eq!(unsafe { *grid.get_unchecked(2) }, 'c');
+    }
+
+    #[test]
+    fn test_get() {
+        let mut grid = build_grid();
+
+        assert_eq!(grid.get(&Point::new(1, 0)), Some(&'b'));
+        assert_eq!(grid.get(&Point::new(3, 0)), None);
+        assert_eq!(grid.get(&Point::new(0, -1)), None);
+
+        *grid.get_mut(&Point::new(1, 0)).unwrap() = 'x';
+        assert_eq!(grid[Point::new(1, 0)], 'x');
+        assert!(grid.get_mut(&Point::new(0, 2)).is_none());
+    }
+
     #[test]
     fn test_is_point_in_grid() {
         let grid = build_grid();
@@ -502,6 +656,77 @@ mod tests {
         assert!(result.is_none(), "result: {result:?}");
     }
 
+    #[test]
+    fn test_wrap_point() {
+        let grid = build_grid();
+
+        assert_eq!(grid.wrap_point(&Point::new(1, 1)), Point::new(1, 1));
+        assert_eq!(grid.wrap_point(&Point::new(3, 2)), Point::new(0, 0));
+        assert_eq!(grid.wrap_point(&Point::new(-1, -1)), Point::new(2, 1));
+        assert_eq!(grid.wrap_point(&Point::new(-7, 5)), Point::new(2, 1));
+    }
+
+    #[test]
+    fn test_neighbors_wrapped() {
+        let grid = build_grid();
+
+        assert_eq!(
+            grid.neighbors_wrapped(&Point::new(0, 0), &Direction::CARDINAL),
+            vec![
+                (Point::new(0, 1), Direction::North),
+                (Point::new(1, 0), Direction::East),
+                (Point::new(0, 1), Direction::South),
+                (Point::new(2, 0), Direction::West)
+            ]
+        );
+        assert_eq!(
+            grid.neighbor_wrapped(&Point::new(2, 1), &Direction::SouthEast),
+            (Point::new(0, 0), Direction::SouthEast)
+        );
+    }
+
+    #[test]
+    fn test_neighbors_wrapped_if() {
+        let grid = build_grid();
+
+        assert_eq!(
+            grid.neighbors_wrapped_if(&Point::new(0, 0), &Direction::CARDINAL, |p, _| {
+                grid[*p] != 'd'
+            }),
+            vec![
+                (Point::new(1, 0), Direction::East),
+                (Point::new(2, 0), Direction::West)
+            ]
+        );
+        assert!(
+            grid.neighbor_wrapped_if(&Point::new(0, 0), &Direction::West, |_, _| false)
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_neighbor_wrapped_matches_rotations() {
+        let original = Grid::new_with(3, 4, |p| p.y * 4 + p.x).unwrap();
+
+        // Rotating row right moves every value one cell east
+        let mut rotated = original.clone();
+        rotated.row_rotate_right(1, 1);
+        for x in 0..4 {
+            let point = Point::new(x, 1);
+            let (moved, _) = original.neighbor_wrapped(&point, &Direction::East);
+            assert_eq!(rotated[moved], original[point]);
+        }
+
+        // Rotating column up moves every value one cell north
+        let mut rotated = original.clone();
+        rotated.col_rotate_up(2, 1);
+        for y in 0..3 {
+            let point = Point::new(2, y);
+            let (moved, _) = original.neighbor_wrapped(&point, &Direction::North);
+            assert_eq!(rotated[moved], original[point]);
+        }
+    }
+
     #[test]
     fn test_get_value() {
         let lines = vec![
@@ -572,6 +797,32 @@ mod tests {
         assert_eq!(grid[Point { x: 2, y: 1 }], '.');
     }
 
+    #[test]
+    fn test_generic_grid() {
+        let mut grid = Grid::new_with(2, 3, |p| (p.x + p.y) as usize).unwrap();
+
+        assert_eq!(grid[Point::new(2, 1)], 3);
+        assert_eq!(grid.get_value(1), vec![Point::new(1, 0), Point::new(0, 1)]);
+        assert_eq!(grid.get_if(|value| value > 2), vec![Point::new(2, 1)]);
+
+        grid.row_rotate_left(0, 1);
+        grid.col_shift_down(2, 1, 42);
+        assert_eq!(
+            grid,
+            Grid::new(vec![vec![1, 2, 42], vec![1, 2, 0]]).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_map() {
+        let grid = build_grid();
+
+        let mapped = grid.map(|c| *c == 'e');
+        assert_eq!(mapped.rows(), 2);
+        assert_eq!(mapped.cols(), 3);
+        assert_eq!(mapped.get_value(true), vec![Point::new(1, 1)]);
+    }
+
     #[test]
     fn test_equals() {
         let grid = build_grid();
@@ -0,0 +1,352 @@
+use std::{
+    collections::HashMap,
+    error::Error,
+    fs::{self, File},
+    io::{BufWriter, Write},
+    path::{Path, PathBuf},
+};
+
+use super::{grid::Grid, point::Point, terminal::Color};
+
+/// Mapping of cell characters to colors, characters without color use the default color
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Palette {
+    colors: HashMap<char, Color>,
+    default: Color,
+}
+
+impl Palette {
+    pub fn new(default: Color) -> Self {
+        Self {
+            colors: HashMap::new(),
+            default,
+        }
+    }
+
+    pub fn color(mut self, c: char, color: Color) -> Self {
+        self.colors.insert(c, color);
+        self
+    }
+
+    pub fn color_of(&self, c: char) -> Color {
+        *self.colors.get(&c).unwrap_or(&self.default)
+    }
+}
+
+/// Supported image file formats
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageFormat {
+    /// Binary portable pixmap, no dependency needed
+    Ppm,
+    /// Requires cargo feature 'png'
+    #[cfg(feature = "png")]
+    Png,
+}
+
+impl ImageFormat {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ImageFormat::Ppm => "ppm",
+            #[cfg(feature = "png")]
+            ImageFormat::Png => "png",
+        }
+    }
+}
+
+/// RGB image, every grid cell is rendered as scale x scale block of pixels
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    width: usize,
+    height: usize,
+    pixels: Vec<u8>,
+}
+
+impl Image {
+    /// Render grid with colors of the palette, scale must be at least 1
+    pub fn from_grid(grid: &Grid, palette: &Palette, scale: usize) -> Result<Self, Box<dyn Error>> {
+        Self::render(grid.rows(), grid.cols(), scale, |p| {
+            palette.color_of(grid[p])
+        })
+    }
+
+    /// Render points within the area of rows x cols cells starting at (0, 0), points outside of
+    /// the area are ignored. Scale must be at least 1.
+    pub fn from_points<'a, I>(
+        points: I,
+        rows: usize,
+        cols: usize,
+        foreground: Color,
+        background: Color,
+        scale: usize,
+    ) -> Result<Self, Box<dyn Error>>
+    where
+        I: IntoIterator<Item = &'a Point>,
+    {
+        let mut lit = vec![false; rows * cols];
+        for point in points {
+            if point.x >= 0
+                && point.y >= 0
+                && (point.x as usize) < cols
+                && (point.y as usize) < rows
+            {
+                lit[point.y as usize * cols + point.x as usize] = true;
+            }
+        }
+
+        Self::render(rows, cols, scale, |p| {
+            match lit[p.y as usize * cols + p.x as usize] {
+                true => foreground,
+                false => background,
+            }
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// RGB components of all pixels, row by row
+    pub fn pixels(&self) -> &[u8] {
+        &self.pixels
+    }
+
+    /// Write image in binary PPM (P6) format
+    pub fn write_ppm<W: Write>(&self, out: &mut W) -> Result<(), Box<dyn Error>> {
+        write!(out, "P6\n{} {}\n255\n", self.width, self.height)?;
+        out.write_all(&self.pixels)?;
+        Ok(())
+    }
+
+    /// Write image in PNG format
+    #[cfg(feature = "png")]
+    pub fn write_png<W: Write>(&self, out: &mut W) -> Result<(), Box<dyn Error>> {
+        let mut encoder = png::Encoder::new(out, self.width as u32, self.height as u32);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+
+        let mut writer = encoder.write_header()?;
+        writer.write_image_data(&self.pixels)?;
+        Ok(())
+    }
+
+    /// Save image to the file in the given format
+    pub fn save<P: AsRef<Path>>(&self, path: P, format: ImageFormat) -> Result<(), Box<dyn Error>> {
+        let path = path.as_ref();
+        let file = File::create(path)
+            .map_err(|err| format!("Failed to create image '{}' [{err}]", path.display()))?;
+        let mut out = BufWriter::new(file);
+
+        match format {
+            ImageFormat::Ppm => self.write_ppm(&mut out)?,
+            #[cfg(feature = "png")]
+            ImageFormat::Png => self.write_png(&mut out)?,
+        }
+
+        out.flush()?;
+        Ok(())
+    }
+
+    fn render<F>(
+        rows: usize,
+        cols: usize,
+        scale: usize,
+        color_of: F,
+    ) -> Result<Self, Box<dyn Error>>
+    where
+        F: Fn(Point) -> Color,
+    {
+        if scale == 0 {
+            return Err("Image scale must be at least 1, got 0".into());
+        }
+
+        let (width, height) = (cols * scale, rows * scale);
+        let mut pixels = Vec::with_capacity(width * height * 3);
+
+        for y in 0..rows {
+            // Render row of cells once and repeat it for every pixel row of the cell
+            let row = (0..cols)
+                .flat_map(|x| {
+                    let rgb = color_of(Point::new(x as isize, y as isize)).rgb();
+                    std::iter::repeat_n(rgb, scale).flatten()
+                })
+                .collect::<Vec<_>>();
+
+            for _ in 0..scale {
+                pixels.extend_from_slice(&row);
+            }
+        }
+
+        Ok(Self {
+            width,
+            height,
+            pixels,
+        })
+    }
+}
+
+/// Saves images as numbered files to the directory, e.g. frame_00000.ppm, frame_00001.ppm, ...
+#[derive(Debug, Clone)]
+pub struct FrameSequence {
+    dir: PathBuf,
+    prefix: String,
+    format: ImageFormat,
+    next: usize,
+}
+
+impl FrameSequence {
+    /// Directory is created if it does not exist
+    pub fn new<P: AsRef<Path>>(
+        dir: P,
+        prefix: &str,
+        format: ImageFormat,
+    ) -> Result<Self, Box<dyn Error>> {
+        let dir = dir.as_ref().to_path_buf();
+        fs::create_dir_all(&dir)
+            .map_err(|err| format!("Failed to create directory '{}' [{err}]", dir.display()))?;
+
+        Ok(Self {
+            dir,
+            prefix: prefix.to_string(),
+            format,
+            next: 0,
+        })
+    }
+
+    /// Number of the next frame
+    pub fn next_number(&self) -> usize {
+        self.next
+    }
+
+    /// Save image as the next frame, path of the saved file is returned
+    pub fn save(&mut self, image: &Image) -> Result<PathBuf, Box<dyn Error>> {
+        self.save_as(self.next, image)
+    }
+
+    /// Save image with the given number, e.g. simulation step. Following frames continue from it.
+    pub fn save_as(&mut self, number: usize, image: &Image) -> Result<PathBuf, Box<dyn Error>> {
+        let path = self.dir.join(format!(
+            "{}_{number:05}.{}",
+            self.prefix,
+            self.format.extension()
+        ));
+
+        image.save(&path, self.format)?;
+        self.next = number + 1;
+        Ok(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+
+    fn build_palette() -> Palette {
+        Palette::new(Color::Black).color('#', Color::Rgb(10, 20, 30))
+    }
+
+    fn temp_dir(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("puzzler_{name}_{}", std::process::id()))
+    }
+
+    #[test]
+    fn test_from_grid() {
+        let grid = Grid::new(vec![vec!['#', '.']]).unwrap();
+
+        let image = Image::from_grid(&grid, &build_palette(), 1).unwrap();
+        assert_eq!((image.width(), image.height()), (2, 1));
+        assert_eq!(image.pixels(), &[10, 20, 30, 0, 0, 0]);
+    }
+
+    #[test]
+    fn test_scale() {
+        let grid = Grid::new(vec![vec!['#', '.']]).unwrap();
+
+        let image = Image::from_grid(&grid, &build_palette(), 2).unwrap();
+        assert_eq!((image.width(), image.height()), (4, 2));
+
+        let row = [10, 20, 30, 10, 20, 30, 0, 0, 0, 0, 0, 0];
+        assert_eq!(image.pixels(), [row, row].concat());
+    }
+
+    #[test]
+    fn test_zero_scale() {
+        let grid = Grid::new(vec![vec!['#', '.']]).unwrap();
+
+        let result = Image::from_grid(&grid, &build_palette(), 0);
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Image scale must be at least 1, got 0"
+        );
+
+        let result = Image::from_points(&[Point::new(0, 0)], 2, 2, Color::White, Color::Black, 0);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_from_points() {
+        let points = HashSet::from([Point::new(1, 0), Point::new(5, 5), Point::new(-1, 0)]);
+
+        let image = Image::from_points(&points, 2, 2, Color::White, Color::Black, 1).unwrap();
+        assert_eq!(image.pixels(), &[0, 0, 0, 255, 255, 255, 0, 0, 0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn test_write_ppm() {
+        let grid = Grid::new(vec![vec!['#'], vec!['.']]).unwrap();
+        let image = Image::from_grid(&grid, &build_palette(), 1).unwrap();
+
+        let mut out = Vec::new();
+        image.write_ppm(&mut out).unwrap();
+
+        let mut expected = b"P6\n1 2\n255\n".to_vec();
+        expected.extend_from_slice(&[10, 20, 30, 0, 0, 0]);
+        assert_eq!(out, expected);
+    }
+
+    #[test]
+    fn test_frame_sequence() {
+        let dir = temp_dir("frames");
+        let grid = Grid::new(vec![vec!['#', '.']]).unwrap();
+        let image = Image::from_grid(&grid, &build_palette(), 1).unwrap();
+
+        let mut frames = FrameSequence::new(&dir, "frame", ImageFormat::Ppm).unwrap();
+        let first = frames.save(&image).unwrap();
+        let second = frames.save_as(42, &image).unwrap();
+        let third = frames.save(&image).unwrap();
+
+        assert_eq!(first, dir.join("frame_00000.ppm"));
+        assert_eq!(second, dir.join("frame_00042.ppm"));
+        assert_eq!(third, dir.join("frame_00043.ppm"));
+        assert_eq!(frames.next_number(), 44);
+        assert_eq!(fs::read(&first).unwrap().len(), "P6\n2 1\n255\n".len() + 6);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[cfg(feature = "png")]
+    #[test]
+    fn test_save_png() {
+        let dir = temp_dir("png");
+        let grid = Grid::new(vec![vec!['#', '.']]).unwrap();
+        let image = Image::from_grid(&grid, &build_palette(), 3).unwrap();
+
+        let path = FrameSequence::new(&dir, "frame", ImageFormat::Png)
+            .unwrap()
+            .save(&image)
+            .unwrap();
+
+        let decoder = png::Decoder::new(File::open(&path).unwrap());
+        let mut reader = decoder.read_info().unwrap();
+        let mut pixels = vec![0; reader.output_buffer_size()];
+        reader.next_frame(&mut pixels).unwrap();
+        assert_eq!(pixels, image.pixels());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -1,3 +1,14 @@
+pub mod bfs;
 pub mod direction;
 pub mod grid;
+pub mod image;
+pub mod pattern;
 pub mod point;
+pub mod regions;
+pub mod render;
+pub mod shortest_path;
+pub mod sparse_grid;
+pub mod subgrid;
+pub mod terminal;
+pub mod tiled;
+pub mod transform;
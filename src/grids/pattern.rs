@@ -0,0 +1,219 @@
+use super::{direction::Direction, grid::Grid, point::Point};
+
+impl<T> Grid<T> {
+    /// Find all occurrences of the sequence going from the start point in one of the directions
+    pub fn find_sequence<U>(
+        &self,
+        sequence: &[U],
+        directions: &[Direction],
+    ) -> Vec<(Point, Direction)>
+    where
+        T: PartialEq<U>,
+    {
+        if sequence.is_empty() {
+            return Vec::new();
+        }
+
+        (0..self.rows() * self.cols())
+            .map(|index| self.point_of(index))
+            .flat_map(|start| {
+                directions
+                    .iter()
+                    .filter(move |direction| {
+                        let mut point = start;
+
+                        for (i, value) in sequence.iter().enumerate() {
+                            if i > 0 {
+                                point = point.neighbor(direction);
+                            }
+
+                            if self.get(&point).is_none_or(|cell| cell != value) {
+                                return false;
+                            }
+                        }
+                        true
+                    })
+                    .map(move |direction| (start, *direction))
+            })
+            .collect()
+    }
+
+    /// Find top left points of all placements of the pattern which are fully in the grid and for
+    /// which matches(cell, pattern cell) is true for every pattern cell
+    pub fn find_pattern_by<U, F>(&self, pattern: &Grid<U>, matches: F) -> Vec<Point>
+    where
+        F: Fn(&T, &U) -> bool,
+    {
+        if pattern.rows() > self.rows() || pattern.cols() > self.cols() {
+            return Vec::new();
+        }
+
+        let mut found = Vec::new();
+
+        for y in 0..=self.rows() - pattern.rows() {
+            for x in 0..=self.cols() - pattern.cols() {
+                let matched = (0..pattern.rows() * pattern.cols()).all(|index| {
+                    let offset = pattern.point_of(index);
+                    let point = Point::new(x as isize + offset.x, y as isize + offset.y);
+
+                    matches(&self[point], &pattern[index])
+                });
+
+                if matched {
+                    found.push(Point::new(x as isize, y as isize));
+                }
+            }
+        }
+
+        found
+    }
+}
+
+impl Grid<char> {
+    /// Find all occurrences of the word going from the start point in one of the directions
+    pub fn find_word(&self, word: &str, directions: &[Direction]) -> Vec<(Point, Direction)> {
+        self.find_sequence(&word.chars().collect::<Vec<_>>(), directions)
+    }
+
+    /// Find top left points of all placements of the pattern, wildcard in the pattern matches any
+    /// character
+    pub fn find_pattern(&self, pattern: &Grid<char>, wildcard: char) -> Vec<Point> {
+        self.find_pattern_by(pattern, |cell, expected| {
+            *expected == wildcard || cell == expected
+        })
+    }
+
+    /// Same as find_pattern(), but the pattern is tried in all its distinct rotations and flips.
+    /// Every match is returned together with the oriented pattern which matched.
+    pub fn find_pattern_oriented(
+        &self,
+        pattern: &Grid<char>,
+        wildcard: char,
+    ) -> Vec<(Point, Grid<char>)> {
+        let mut orientations = Vec::new();
+        for orientation in pattern.orientations() {
+            if !orientations.contains(&orientation) {
+                orientations.push(orientation);
+            }
+        }
+
+        orientations
+            .into_iter()
+            .flat_map(|orientation| {
+                self.find_pattern(&orientation, wildcard)
+                    .into_iter()
+                    .map(move |point| (point, orientation.clone()))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parsers::parser::Parser;
+
+    use super::*;
+
+    fn build_grid(lines: &[&str]) -> Grid {
+        Parser::parse_lines_to_grid_str(lines).unwrap()
+    }
+
+    fn build_word_search() -> Grid {
+        build_grid(&[
+            "MMMSXXMASM",
+            "MSAMXMSMSA",
+            "AMXSXMAAMM",
+            "MSAMASMSMX",
+            "XMASAMXAMM",
+            "XXAMMXXAMA",
+            "SMSMSASXSS",
+            "SAXAMASAAA",
+            "MAMMMXMMMM",
+            "MXMXAXMASX",
+        ])
+    }
+
+    #[test]
+    fn test_find_word() {
+        let grid = build_word_search();
+
+        assert_eq!(grid.find_word("XMAS", &Direction::ALL).len(), 18);
+        assert_eq!(grid.find_word("XMAS", &[Direction::East]).len(), 3);
+        assert!(
+            grid.find_word("XMAS", &Direction::ALL)
+                .contains(&(Point::new(5, 0), Direction::East))
+        );
+        assert!(grid.find_word("", &Direction::ALL).is_empty());
+    }
+
+    #[test]
+    fn test_find_word_single_letter() {
+        let grid = build_grid(&["ab", "ba"]);
+
+        assert_eq!(
+            grid.find_word("a", &[Direction::North]),
+            vec![
+                (Point::new(0, 0), Direction::North),
+                (Point::new(1, 1), Direction::North)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_find_sequence() {
+        let grid = Grid::new(vec![vec![1, 2, 3], vec![2, 3, 4], vec![3, 4, 5]]).unwrap();
+
+        assert_eq!(
+            grid.find_sequence(&[3, 4, 5], &Direction::CARDINAL),
+            vec![
+                (Point::new(2, 0), Direction::South),
+                (Point::new(0, 2), Direction::East)
+            ]
+        );
+        assert_eq!(
+            grid.find_sequence(&[1, 3, 5], &Direction::ALL),
+            vec![(Point::new(0, 0), Direction::SouthEast)]
+        );
+    }
+
+    #[test]
+    fn test_find_pattern() {
+        let grid = build_grid(&["..#...", ".###..", "..#.#.", "...###", "....#."]);
+        let pattern = build_grid(&[" # ", "###", " # "]);
+
+        assert_eq!(
+            grid.find_pattern(&pattern, ' '),
+            vec![Point::new(1, 0), Point::new(3, 2)]
+        );
+        assert!(grid.find_pattern(&build_grid(&["######"]), ' ').is_empty());
+    }
+
+    #[test]
+    fn test_find_pattern_sea_monster() {
+        let monster = build_grid(&[
+            "                  # ",
+            "#    ##    ##    ###",
+            " #  #  #  #  #  #   ",
+        ]);
+        let grid = build_grid(&[
+            "....................#...",
+            "..#....##....##....###..",
+            "...#..#..#..#..#..#.....",
+        ]);
+
+        assert_eq!(grid.find_pattern(&monster, ' '), vec![Point::new(2, 0)]);
+        assert!(grid.flip_vertical().find_pattern(&monster, ' ').is_empty());
+
+        let found = grid.flip_vertical().find_pattern_oriented(&monster, ' ');
+        assert_eq!(found, vec![(Point::new(2, 0), monster.flip_vertical())]);
+    }
+
+    #[test]
+    fn test_find_pattern_oriented() {
+        let grid = build_word_search();
+        let pattern = build_grid(&["M.S", ".A.", "M.S"]);
+
+        assert_eq!(grid.find_pattern(&pattern, '.').len(), 2);
+        assert_eq!(grid.find_pattern_oriented(&pattern, '.').len(), 9);
+    }
+}
@@ -0,0 +1,309 @@
+use std::collections::VecDeque;
+
+use super::{direction::Direction, grid::Grid, point::Point};
+
+/// Connected region of the grid
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Region {
+    pub id: usize,
+    /// Points of the region in order of the flood fill
+    pub points: Vec<Point>,
+    /// Number of cell edges on the region border
+    pub perimeter: usize,
+    /// Number of straight sides of the region border, including borders of holes
+    pub sides: usize,
+}
+
+impl Region {
+    pub fn area(&self) -> usize {
+        self.points.len()
+    }
+}
+
+/// Grid split into connected regions, every cell belongs to exactly one region
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Regions {
+    labels: Grid<usize>,
+    regions: Vec<Region>,
+}
+
+impl Regions {
+    /// Region id of every cell
+    pub fn labels(&self) -> &Grid<usize> {
+        &self.labels
+    }
+
+    pub fn label(&self, point: &Point) -> Option<usize> {
+        self.labels.get(point).copied()
+    }
+
+    pub fn regions(&self) -> &[Region] {
+        &self.regions
+    }
+
+    pub fn region_of(&self, point: &Point) -> Option<&Region> {
+        self.label(point).map(|id| &self.regions[id])
+    }
+
+    pub fn len(&self) -> usize {
+        self.regions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.regions.is_empty()
+    }
+}
+
+impl<T> Grid<T> {
+    /// Collect all points connected to the start point. Neighbor in one of the directions is
+    /// connected if same(point, neighbor) is true.
+    pub fn flood_fill<F>(&self, start: &Point, directions: &[Direction], same: F) -> Vec<Point>
+    where
+        F: Fn(&Point, &Point) -> bool,
+    {
+        let mut visited = self.map(|_| false);
+        self.fill_from(start, directions, &same, &mut visited)
+    }
+
+    /// Split grid into regions of the same value. Use Direction::CARDINAL for 4-connectivity or
+    /// Direction::ALL for 8-connectivity.
+    pub fn regions(&self, directions: &[Direction]) -> Regions
+    where
+        T: PartialEq,
+    {
+        self.regions_by(directions, |a, b| self[*a] == self[*b])
+    }
+
+    /// Split grid into regions, neighbors belong to the same region if same(point, neighbor) is
+    /// true. Predicate should be symmetric, regions are filled in row-major order.
+    pub fn regions_by<F>(&self, directions: &[Direction], same: F) -> Regions
+    where
+        F: Fn(&Point, &Point) -> bool,
+    {
+        let mut visited = self.map(|_| false);
+        let mut labels = self.map(|_| 0);
+        let mut regions = Vec::new();
+
+        for index in 0..self.rows() * self.cols() {
+            if visited[index] {
+                continue;
+            }
+
+            let start = self.point_of(index);
+            let points = self.fill_from(&start, directions, &same, &mut visited);
+            for point in &points {
+                labels[*point] = regions.len();
+            }
+
+            regions.push(Region {
+                id: regions.len(),
+                points,
+                perimeter: 0,
+                sides: 0,
+            });
+        }
+
+        for region in regions.iter_mut() {
+            region.perimeter = Self::perimeter(&labels, region);
+            region.sides = Self::sides(&labels, region);
+        }
+
+        Regions { labels, regions }
+    }
+
+    fn fill_from<F>(
+        &self,
+        start: &Point,
+        directions: &[Direction],
+        same: &F,
+        visited: &mut Grid<bool>,
+    ) -> Vec<Point>
+    where
+        F: Fn(&Point, &Point) -> bool,
+    {
+        if !self.is_point_in_grid(start) {
+            return Vec::new();
+        }
+
+        let mut points = vec![*start];
+        let mut queue = VecDeque::from([*start]);
+        visited[*start] = true;
+
+        while let Some(point) = queue.pop_front() {
+            for (neighbor, _) in self.neighbors_if(&point, directions, |n, _| same(&point, n)) {
+                if !visited[neighbor] {
+                    visited[neighbor] = true;
+                    points.push(neighbor);
+                    queue.push_back(neighbor);
+                }
+            }
+        }
+
+        points
+    }
+
+    // Count cell edges which are not shared with other cell of the region
+    fn perimeter(labels: &Grid<usize>, region: &Region) -> usize {
+        region
+            .points
+            .iter()
+            .map(|point| {
+                Direction::CARDINAL
+                    .iter()
+                    .filter(|direction| !Self::is_in(labels, region, &point.neighbor(direction)))
+                    .count()
+            })
+            .sum()
+    }
+
+    // Number of sides of a polygon equals to the number of its corners. Every cell is checked for
+    // convex corners (both orthogonal neighbors are outside) and concave corners (both orthogonal
+    // neighbors are inside, diagonal one is outside).
+    fn sides(labels: &Grid<usize>, region: &Region) -> usize {
+        region
+            .points
+            .iter()
+            .map(|point| {
+                Direction::CARDINAL
+                    .iter()
+                    .filter(|direction| {
+                        let side = direction.right();
+                        let a = Self::is_in(labels, region, &point.neighbor(direction));
+                        let b = Self::is_in(labels, region, &point.neighbor(&side));
+                        let diagonal = point.neighbor(direction).neighbor(&side);
+
+                        (!a && !b) || (a && b && !Self::is_in(labels, region, &diagonal))
+                    })
+                    .count()
+            })
+            .sum()
+    }
+
+    fn is_in(labels: &Grid<usize>, region: &Region, point: &Point) -> bool {
+        labels.get(point) == Some(&region.id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parsers::parser::Parser;
+
+    use super::*;
+
+    fn build_grid(lines: &[&str]) -> Grid {
+        Parser::parse_lines_to_grid_str(lines).unwrap()
+    }
+
+    // Sum of area * perimeter and area * sides of all regions
+    fn prices(regions: &Regions) -> (usize, usize) {
+        regions.regions().iter().fold((0, 0), |(p, s), region| {
+            (
+                p + region.area() * region.perimeter,
+                s + region.area() * region.sides,
+            )
+        })
+    }
+
+    #[test]
+    fn test_regions() {
+        let grid = build_grid(&["AAAA", "BBCD", "BBCC", "EEEC"]);
+        let regions = grid.regions(&Direction::CARDINAL);
+
+        assert_eq!(regions.len(), 5);
+
+        let summary = regions
+            .regions()
+            .iter()
+            .map(|r| (grid[r.points[0]], r.area(), r.perimeter, r.sides))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            summary,
+            vec![
+                ('A', 4, 10, 4),
+                ('B', 4, 8, 4),
+                ('C', 4, 10, 8),
+                ('D', 1, 4, 4),
+                ('E', 3, 8, 4),
+            ]
+        );
+        assert_eq!(prices(&regions), (140, 80));
+    }
+
+    #[test]
+    fn test_regions_labels() {
+        let grid = build_grid(&["AAAA", "BBCD", "BBCC", "EEEC"]);
+        let regions = grid.regions(&Direction::CARDINAL);
+
+        assert_eq!(regions.label(&Point::new(3, 3)), Some(2));
+        assert_eq!(regions.label(&Point::new(4, 3)), None);
+        assert_eq!(regions.labels()[Point::new(0, 3)], 4);
+        assert_eq!(regions.region_of(&Point::new(3, 1)).unwrap().area(), 1);
+    }
+
+    #[test]
+    fn test_regions_with_holes() {
+        let grid = build_grid(&["OOOOO", "OXOXO", "OOOOO", "OXOXO", "OOOOO"]);
+        let regions = grid.regions(&Direction::CARDINAL);
+
+        assert_eq!(regions.len(), 5);
+        assert_eq!(prices(&regions), (772, 436));
+    }
+
+    #[test]
+    fn test_regions_sides() {
+        let grid = build_grid(&["EEEEE", "EXXXX", "EEEEE", "EXXXX", "EEEEE"]);
+        assert_eq!(prices(&grid.regions(&Direction::CARDINAL)).1, 236);
+
+        let grid = build_grid(&["AAAAAA", "AAABBA", "AAABBA", "ABBAAA", "ABBAAA", "AAAAAA"]);
+        assert_eq!(prices(&grid.regions(&Direction::CARDINAL)).1, 368);
+    }
+
+    #[test]
+    fn test_regions_all_directions() {
+        let grid = build_grid(&["#..", ".#.", "..#"]);
+
+        assert_eq!(grid.regions(&Direction::CARDINAL).len(), 5);
+
+        let regions = grid.regions(&Direction::ALL);
+        assert_eq!(regions.len(), 2);
+        assert_eq!(regions.region_of(&Point::new(2, 2)).unwrap().area(), 3);
+    }
+
+    #[test]
+    fn test_regions_by() {
+        // Digits below 9 form basins separated by 9s
+        let lines = ["2199943210", "3987894921", "9856789892"];
+        let grid = Parser::parse_lines_to_digit_grid(lines.iter().map(|l| l.to_string()).collect())
+            .unwrap();
+
+        let regions = grid.regions_by(&Direction::CARDINAL, |a, b| {
+            (grid[*a] == 9) == (grid[*b] == 9)
+        });
+        let basins = regions
+            .regions()
+            .iter()
+            .filter(|r| grid[r.points[0]] != 9)
+            .map(Region::area)
+            .collect::<Vec<_>>();
+
+        assert_eq!(basins, vec![3, 9, 8, 1]);
+    }
+
+    #[test]
+    fn test_flood_fill() {
+        let grid = build_grid(&["..#", ".##", "#.."]);
+
+        let points = grid.flood_fill(&Point::new(0, 0), &Direction::CARDINAL, |_, b| {
+            grid[*b] == '.'
+        });
+        assert_eq!(
+            points,
+            vec![Point::new(0, 0), Point::new(1, 0), Point::new(0, 1)]
+        );
+
+        assert!(
+            grid.flood_fill(&Point::new(5, 0), &Direction::CARDINAL, |_, _| true)
+                .is_empty()
+        );
+    }
+}
@@ -0,0 +1,153 @@
+use std::{
+    collections::HashSet,
+    fmt::{self, Display},
+    io,
+};
+
+use super::{grid::Grid, point::Point};
+
+/// Render grid of characters with overlay layers, e.g. visited points or current positions.
+/// Layers are drawn in order of adding, so the last added layer is on top.
+pub struct Renderer<'a> {
+    grid: &'a Grid,
+    layers: Vec<(&'a HashSet<Point>, char)>,
+}
+
+impl<'a> Renderer<'a> {
+    pub fn new(grid: &'a Grid) -> Self {
+        Self {
+            grid,
+            layers: Vec::new(),
+        }
+    }
+
+    /// Add layer, all points of the layer are rendered as the given character
+    pub fn layer(mut self, points: &'a HashSet<Point>, c: char) -> Self {
+        self.layers.push((points, c));
+        self
+    }
+
+    /// Character rendered at the point
+    pub fn char_at(&self, point: &Point) -> char {
+        self.layers
+            .iter()
+            .rev()
+            .find(|(points, _)| points.contains(point))
+            .map_or(self.grid[*point], |(_, c)| *c)
+    }
+
+    /// Write rows of the grid separated by new lines, last row is not terminated
+    pub fn write_to<W: fmt::Write>(&self, out: &mut W) -> fmt::Result {
+        for y in 0..self.grid.rows() {
+            if y > 0 {
+                out.write_char('\n')?;
+            }
+
+            for x in 0..self.grid.cols() {
+                out.write_char(self.char_at(&Point::new(x as isize, y as isize)))?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Write rows of the grid to the stream, every row is terminated by new line
+    pub fn write_io<W: io::Write>(&self, out: &mut W) -> io::Result<()> {
+        let mut line = String::with_capacity(self.grid.cols());
+
+        for y in 0..self.grid.rows() {
+            line.clear();
+            line.extend(
+                (0..self.grid.cols()).map(|x| self.char_at(&Point::new(x as isize, y as isize))),
+            );
+            writeln!(out, "{line}")?;
+        }
+
+        Ok(())
+    }
+
+    /// Print grid to the console
+    pub fn print(&self) {
+        // Lock stdout once for the whole grid to speedup writes
+        let _ = self.write_io(&mut io::stdout().lock());
+    }
+}
+
+impl Display for Renderer<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_to(f)
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for index in 0..self.rows() * self.cols() {
+            if index > 0 && index % self.cols() == 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{}", self[index])?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build_grid() -> Grid {
+        Grid::new(vec![vec!['.', '.', '#'], vec!['#', '.', '.']]).unwrap()
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(build_grid().to_string(), "..#\n#..");
+
+        let digits = Grid::new(vec![vec![1, 2], vec![3, 4]]).unwrap();
+        assert_eq!(format!("{digits}"), "12\n34");
+    }
+
+    #[test]
+    fn test_layers() {
+        let grid = build_grid();
+        let visited = HashSet::from([Point::new(0, 0), Point::new(1, 0), Point::new(1, 1)]);
+        let current = HashSet::from([Point::new(1, 1)]);
+
+        let renderer = Renderer::new(&grid)
+            .layer(&visited, 'O')
+            .layer(&current, '@');
+
+        assert_eq!(renderer.to_string(), "OO#\n#@.");
+        assert_eq!(renderer.char_at(&Point::new(2, 0)), '#');
+        assert_eq!(Renderer::new(&grid).to_string(), grid.to_string());
+    }
+
+    #[test]
+    fn test_write_to() {
+        let grid = build_grid();
+        let visited = HashSet::from([Point::new(2, 1)]);
+
+        let mut out = String::new();
+        Renderer::new(&grid)
+            .layer(&visited, 'x')
+            .write_to(&mut out)
+            .unwrap();
+
+        assert_eq!(out, "..#\n#.x");
+    }
+
+    #[test]
+    fn test_write_io() {
+        let grid = build_grid();
+        let visited = HashSet::from([Point::new(0, 1)]);
+
+        let mut out = Vec::new();
+        Renderer::new(&grid)
+            .layer(&visited, 'O')
+            .write_io(&mut out)
+            .unwrap();
+
+        assert_eq!(String::from_utf8(out).unwrap(), "..#\nO..\n");
+    }
+}
@@ -0,0 +1,195 @@
+use crate::search::dijkstra::{Dijkstra, ShortestPaths};
+
+use super::{direction::Direction, grid::Grid, point::Point};
+
+impl<T> Grid<T> {
+    /// Find the cheapest paths from any start point to the goal. Step from one point to its
+    /// neighbor in one of the directions costs cost(from, to), None if the step is not possible.
+    pub fn shortest_paths<F>(
+        &self,
+        starts: &[Point],
+        directions: &[Direction],
+        cost: F,
+        goal: &Point,
+    ) -> ShortestPaths<Point>
+    where
+        F: Fn(&Point, &Point) -> Option<usize>,
+    {
+        Dijkstra::search(
+            starts
+                .iter()
+                .filter(|start| self.is_point_in_grid(start))
+                .copied(),
+            |point| {
+                directions
+                    .iter()
+                    .map(|direction| point.neighbor(direction))
+                    .filter(|neighbor| self.is_point_in_grid(neighbor))
+                    .filter_map(|neighbor| cost(point, &neighbor).map(|cost| (neighbor, cost)))
+                    .collect::<Vec<_>>()
+            },
+            |point| point == goal,
+        )
+    }
+
+    /// Find the cheapest paths of the walker which starts at given point facing given direction.
+    /// Walker can step forward for 'forward' cost if passable(from, to) is true, or it can turn
+    /// left or right in place for 'turn' cost. Goal can be reached facing any direction.
+    pub fn shortest_paths_facing<F>(
+        &self,
+        start: (Point, Direction),
+        passable: F,
+        forward: usize,
+        turn: usize,
+        goal: &Point,
+    ) -> ShortestPaths<(Point, Direction)>
+    where
+        F: Fn(&Point, &Point) -> bool,
+    {
+        Dijkstra::search(
+            [start],
+            |(point, direction)| {
+                let mut successors = vec![
+                    ((*point, direction.left()), turn),
+                    ((*point, direction.right()), turn),
+                ];
+
+                let next = point.neighbor(direction);
+                if self.is_point_in_grid(&next) && passable(point, &next) {
+                    successors.push(((next, *direction), forward));
+                }
+                successors
+            },
+            |(point, _)| point == goal,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use crate::parsers::parser::Parser;
+
+    use super::*;
+
+    fn to_lines(raw: &str) -> Vec<String> {
+        raw.lines().map(String::from).collect()
+    }
+
+    #[test]
+    fn test_shortest_paths() {
+        let lines = to_lines(
+            "1163751742\n1381373672\n2136511328\n3694931569\n7463417111\n\
+             1319128137\n1359912421\n3125421639\n1293138521\n2311944581",
+        );
+        let grid = Parser::parse_lines_to_digit_grid(lines).unwrap();
+
+        let result = grid.shortest_paths(
+            &[Point::new(0, 0)],
+            &Direction::CARDINAL,
+            |_, to| Some(grid[*to] as usize),
+            &Point::new(9, 9),
+        );
+
+        assert_eq!(result.cost(), Some(40));
+
+        let path = result.path().unwrap();
+        assert_eq!(path[0], Point::new(0, 0));
+        assert_eq!(path[path.len() - 1], Point::new(9, 9));
+    }
+
+    #[test]
+    fn test_shortest_paths_unreachable() {
+        let grid = Parser::parse_lines_to_grid(to_lines("..#.\n..#.")).unwrap();
+
+        let result = grid.shortest_paths(
+            &[Point::new(0, 0)],
+            &Direction::CARDINAL,
+            |_, to| (grid[*to] != '#').then_some(1),
+            &Point::new(3, 1),
+        );
+
+        assert_eq!(result.cost(), None);
+        assert_eq!(result.cost_to(&Point::new(1, 1)), Some(2));
+    }
+
+    #[test]
+    fn test_shortest_paths_facing() {
+        let lines = to_lines(
+            "###############\n\
+             #.......#....E#\n\
+             #.#.###.#.###.#\n\
+             #.....#.#...#.#\n\
+             #.###.#####.#.#\n\
+             #.#.#.......#.#\n\
+             #.#.#####.###.#\n\
+             #...........#.#\n\
+             ###.#.#####.#.#\n\
+             #...#.....#.#.#\n\
+             #.#.#.###.#.#.#\n\
+             #.....#...#.#.#\n\
+             #.###.#.#.#.#.#\n\
+             #S..#.....#...#\n\
+             ###############",
+        );
+        let grid = Parser::parse_lines_to_grid(lines).unwrap();
+        let start = grid.get_value('S')[0];
+        let goal = grid.get_value('E')[0];
+
+        let result = grid.shortest_paths_facing(
+            (start, Direction::East),
+            |_, to| grid[*to] != '#',
+            1,
+            1000,
+            &goal,
+        );
+
+        assert_eq!(result.cost(), Some(7036));
+
+        let tiles = result
+            .states_on_paths()
+            .into_iter()
+            .map(|(point, _)| point)
+            .collect::<HashSet<_>>();
+        assert_eq!(tiles.len(), 45);
+    }
+
+    #[test]
+    fn test_custom_state() {
+        // Crucible can move at most three blocks in one direction, it cannot reverse
+        let lines = to_lines(
+            "2413432311323\n3215453535623\n3255245654254\n3446585845452\n\
+             4546657867536\n1438598798454\n4457876987766\n3637877979653\n\
+             4654967986887\n4564679986453\n1224686865563\n2546548887735\n\
+             4322674655533",
+        );
+        let grid = Parser::parse_lines_to_digit_grid(lines).unwrap();
+        let goal = Point::new(grid.cols() as isize - 1, grid.rows() as isize - 1);
+
+        let result = Dijkstra::search(
+            [
+                (Point::new(0, 0), Direction::East, 0),
+                (Point::new(0, 0), Direction::South, 0),
+            ],
+            |(point, direction, steps)| {
+                let mut turns = vec![(direction.left(), 1), (direction.right(), 1)];
+                if *steps < 3 {
+                    turns.push((*direction, steps + 1));
+                }
+
+                turns
+                    .into_iter()
+                    .filter_map(|(direction, steps)| {
+                        let next = point.neighbor(&direction);
+                        grid.get(&next)
+                            .map(|cost| ((next, direction, steps), *cost as usize))
+                    })
+                    .collect::<Vec<_>>()
+            },
+            |(point, _, _)| *point == goal,
+        );
+
+        assert_eq!(result.cost(), Some(102));
+    }
+}
@@ -0,0 +1,406 @@
+use std::{collections::HashMap, error::Error, ops::Index};
+
+use super::{direction::Direction, grid::Grid, point::Point};
+
+/// Unbounded grid which stores only occupied cells. Bounding box of the occupied cells is tracked
+/// as cells are inserted and removed.
+#[derive(Debug, Clone)]
+pub struct SparseGrid<T = char> {
+    cells: HashMap<Point, T>,
+    min: Point,
+    max: Point,
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        Self {
+            cells: HashMap::new(),
+            min: Point::new(0, 0),
+            max: Point::new(0, 0),
+        }
+    }
+
+    /// Convert dense grid to sparse grid, only cells for which func returns true are kept
+    pub fn from_grid_if<F>(grid: &Grid<T>, func: F) -> Self
+    where
+        T: Clone,
+        F: Fn(&T) -> bool,
+    {
+        let mut sparse = Self::new();
+
+        for index in 0..grid.rows() * grid.cols() {
+            if func(&grid[index]) {
+                sparse.insert(grid.point_of(index), grid[index].clone());
+            }
+        }
+
+        sparse
+    }
+
+    /// Insert value, previous value at the point is returned
+    pub fn insert(&mut self, point: Point, value: T) -> Option<T> {
+        if self.cells.is_empty() {
+            (self.min, self.max) = (point, point);
+        } else {
+            self.min = Point::new(self.min.x.min(point.x), self.min.y.min(point.y));
+            self.max = Point::new(self.max.x.max(point.x), self.max.y.max(point.y));
+        }
+
+        self.cells.insert(point, value)
+    }
+
+    pub fn remove(&mut self, point: &Point) -> Option<T> {
+        let value = self.cells.remove(point)?;
+
+        // Bounding box shrinks only if the point was on its border
+        if point.x == self.min.x
+            || point.x == self.max.x
+            || point.y == self.min.y
+            || point.y == self.max.y
+        {
+            self.update_bounds();
+        }
+
+        Some(value)
+    }
+
+    pub fn get(&self, point: &Point) -> Option<&T> {
+        self.cells.get(point)
+    }
+
+    pub fn get_mut(&mut self, point: &Point) -> Option<&mut T> {
+        self.cells.get_mut(point)
+    }
+
+    pub fn contains(&self, point: &Point) -> bool {
+        self.cells.contains_key(point)
+    }
+
+    /// Number of occupied cells
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&Point, &T)> {
+        self.cells.iter()
+    }
+
+    pub fn points(&self) -> impl Iterator<Item = &Point> {
+        self.cells.keys()
+    }
+
+    /// Top left and bottom right corner of the bounding box (both inclusive), None if empty
+    pub fn bounds(&self) -> Option<(Point, Point)> {
+        match self.cells.is_empty() {
+            true => None,
+            false => Some((self.min, self.max)),
+        }
+    }
+
+    /// Number of rows of the bounding box
+    pub fn rows(&self) -> usize {
+        self.bounds()
+            .map_or(0, |(min, max)| (max.y - min.y + 1) as usize)
+    }
+
+    /// Number of columns of the bounding box
+    pub fn cols(&self) -> usize {
+        self.bounds()
+            .map_or(0, |(min, max)| (max.x - min.x + 1) as usize)
+    }
+
+    /// Check if point is within the bounding box
+    pub fn is_point_in_bounds(&self, point: &Point) -> bool {
+        self.bounds().is_some_and(|(min, max)| {
+            point.x >= min.x && point.x <= max.x && point.y >= min.y && point.y <= max.y
+        })
+    }
+
+    /// Neighbor in the direction, every point has all neighbors in the unbounded grid
+    pub fn neighbor(&self, point: &Point, direction: &Direction) -> (Point, Direction) {
+        (point.neighbor(direction), *direction)
+    }
+
+    pub fn neighbor_if<F>(
+        &self,
+        point: &Point,
+        direction: &Direction,
+        func: F,
+    ) -> Option<(Point, Direction)>
+    where
+        F: Fn(&Point, &Direction) -> bool,
+    {
+        let (neighbor, direction) = self.neighbor(point, direction);
+
+        match func(&neighbor, &direction) {
+            true => Some((neighbor, direction)),
+            false => None,
+        }
+    }
+
+    pub fn neighbors(&self, point: &Point, directions: &[Direction]) -> Vec<(Point, Direction)> {
+        directions
+            .iter()
+            .map(|direction| self.neighbor(point, direction))
+            .collect::<Vec<_>>()
+    }
+
+    pub fn neighbors_if<F>(
+        &self,
+        point: &Point,
+        directions: &[Direction],
+        func: F,
+    ) -> Vec<(Point, Direction)>
+    where
+        F: Fn(&Point, &Direction) -> bool,
+    {
+        directions
+            .iter()
+            .filter_map(|direction| self.neighbor_if(point, direction, &func))
+            .collect::<Vec<_>>()
+    }
+
+    /// Convert to dense grid covering the bounding box, top left corner of the bounding box is
+    /// moved to (0, 0). Cells which are not occupied are filled with the fill value.
+    pub fn to_grid(&self, fill: T) -> Result<Grid<T>, Box<dyn Error>>
+    where
+        T: Clone,
+    {
+        let (min, _) = self.bounds().ok_or("Sparse grid is empty")?;
+
+        Grid::new_with(self.rows(), self.cols(), |p| {
+            match self.cells.get(&Point::new(p.x + min.x, p.y + min.y)) {
+                Some(value) => value.clone(),
+                None => fill.clone(),
+            }
+        })
+    }
+
+    fn update_bounds(&mut self) {
+        let mut points = self.cells.keys();
+
+        if let Some(first) = points.next() {
+            let (min, max) = points.fold((*first, *first), |(min, max), p| {
+                (
+                    Point::new(min.x.min(p.x), min.y.min(p.y)),
+                    Point::new(max.x.max(p.x), max.y.max(p.y)),
+                )
+            });
+            (self.min, self.max) = (min, max);
+        }
+    }
+}
+
+impl<T: PartialEq> SparseGrid<T> {
+    /// Get positions of all values from the grid, positions are sorted by rows
+    pub fn get_value(&self, value: T) -> Vec<Point> {
+        self.get_if(|v| *v == value)
+    }
+
+    pub fn get_if<F>(&self, func: F) -> Vec<Point>
+    where
+        F: Fn(&T) -> bool,
+    {
+        let mut points = self
+            .cells
+            .iter()
+            .filter(|(_, value)| func(value))
+            .map(|(point, _)| *point)
+            .collect::<Vec<_>>();
+        points.sort_by_key(|point| (point.y, point.x));
+        points
+    }
+}
+
+impl SparseGrid<char> {
+    /// Print bounding box of the grid to the console, cells which are not occupied are printed
+    /// as fill character
+    pub fn print(&self, fill: char) {
+        for line in self.to_strings(fill) {
+            println!("{line}");
+        }
+    }
+
+    /// Rows of the bounding box as strings, cells which are not occupied are shown as fill character
+    pub fn to_strings(&self, fill: char) -> Vec<String> {
+        let Some((min, max)) = self.bounds() else {
+            return Vec::new();
+        };
+
+        (min.y..=max.y)
+            .map(|y| {
+                (min.x..=max.x)
+                    .map(|x| *self.cells.get(&Point::new(x, y)).unwrap_or(&fill))
+                    .collect::<String>()
+            })
+            .collect()
+    }
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// Bounding box is derived from the cells, so only cells are compared
+impl<T: PartialEq> PartialEq for SparseGrid<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cells == other.cells
+    }
+}
+
+impl<T: Eq> Eq for SparseGrid<T> {}
+
+impl<T: Clone> From<&Grid<T>> for SparseGrid<T> {
+    fn from(grid: &Grid<T>) -> Self {
+        Self::from_grid_if(grid, |_| true)
+    }
+}
+
+impl<T> Index<Point> for SparseGrid<T> {
+    type Output = T;
+
+    fn index(&self, index: Point) -> &Self::Output {
+        &self.cells[&index]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parsers::parser::Parser;
+
+    use super::*;
+
+    fn build_grid() -> SparseGrid {
+        let mut grid = SparseGrid::new();
+        grid.insert(Point::new(500, 0), '+');
+        grid.insert(Point::new(498, 4), '#');
+        grid.insert(Point::new(498, 6), '#');
+        grid.insert(Point::new(496, 6), '#');
+        grid
+    }
+
+    #[test]
+    fn test_insert() {
+        let mut grid = SparseGrid::new();
+
+        assert!(grid.is_empty());
+        assert_eq!(grid.bounds(), None);
+        assert_eq!(grid.rows(), 0);
+
+        assert_eq!(grid.insert(Point::new(-3, 2), 'a'), None);
+        assert_eq!(grid.insert(Point::new(-3, 2), 'b'), Some('a'));
+        assert_eq!(grid.bounds(), Some((Point::new(-3, 2), Point::new(-3, 2))));
+
+        grid.insert(Point::new(4, -1), 'c');
+        assert_eq!(grid.len(), 2);
+        assert_eq!(grid.bounds(), Some((Point::new(-3, -1), Point::new(4, 2))));
+        assert_eq!(grid.rows(), 4);
+        assert_eq!(grid.cols(), 8);
+        assert_eq!(grid[Point::new(4, -1)], 'c');
+        assert_eq!(grid.get(&Point::new(0, 0)), None);
+        assert!(grid.is_point_in_bounds(&Point::new(0, 0)));
+        assert!(!grid.is_point_in_bounds(&Point::new(5, 0)));
+    }
+
+    #[test]
+    fn test_remove() {
+        let mut grid = build_grid();
+
+        assert_eq!(grid.remove(&Point::new(0, 0)), None);
+        assert_eq!(grid.remove(&Point::new(498, 4)), Some('#'));
+        assert_eq!(
+            grid.bounds(),
+            Some((Point::new(496, 0), Point::new(500, 6)))
+        );
+
+        grid.remove(&Point::new(500, 0));
+        assert_eq!(
+            grid.bounds(),
+            Some((Point::new(496, 6), Point::new(498, 6)))
+        );
+
+        grid.remove(&Point::new(496, 6));
+        grid.remove(&Point::new(498, 6));
+        assert!(grid.is_empty());
+        assert_eq!(grid.bounds(), None);
+        assert_eq!(grid, SparseGrid::default());
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = build_grid();
+
+        assert_eq!(
+            grid.neighbors(&Point::new(0, 0), &[Direction::North, Direction::West]),
+            vec![
+                (Point::new(0, -1), Direction::North),
+                (Point::new(-1, 0), Direction::West)
+            ]
+        );
+        assert_eq!(
+            grid.neighbors_if(&Point::new(497, 5), &Direction::ALL, |p, _| grid
+                .contains(p)),
+            vec![
+                (Point::new(498, 4), Direction::NorthEast),
+                (Point::new(498, 6), Direction::SouthEast),
+                (Point::new(496, 6), Direction::SouthWest)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_get_value() {
+        let grid = build_grid();
+
+        assert_eq!(
+            grid.get_value('#'),
+            vec![Point::new(498, 4), Point::new(496, 6), Point::new(498, 6)]
+        );
+        assert_eq!(grid.get_if(|c| *c == '+'), vec![Point::new(500, 0)]);
+    }
+
+    #[test]
+    fn test_to_strings() {
+        let grid = build_grid();
+
+        assert_eq!(
+            grid.to_strings('.'),
+            vec![
+                "....+", ".....", ".....", ".....", "..#..", ".....", "#.#.."
+            ]
+        );
+        assert!(SparseGrid::<char>::new().to_strings('.').is_empty());
+    }
+
+    #[test]
+    fn test_to_grid() {
+        let grid = build_grid();
+
+        let dense = grid.to_grid('.').unwrap();
+        assert!(dense.equals(&[
+            "....+", ".....", ".....", ".....", "..#..", ".....", "#.#.."
+        ]));
+
+        assert!(SparseGrid::<char>::new().to_grid('.').is_err());
+    }
+
+    #[test]
+    fn test_from_grid() {
+        let lines = vec!["#..".to_string(), ".#.".to_string()];
+        let grid = Parser::parse_lines_to_grid(lines).unwrap();
+
+        let sparse = SparseGrid::from_grid_if(&grid, |c| *c == '#');
+        assert_eq!(sparse.len(), 2);
+        assert_eq!(sparse.bounds(), Some((Point::new(0, 0), Point::new(1, 1))));
+
+        let sparse = SparseGrid::from(&grid);
+        assert_eq!(sparse.len(), 6);
+        assert_eq!(sparse.to_grid(' ').unwrap(), grid);
+    }
+}
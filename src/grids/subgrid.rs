@@ -0,0 +1,221 @@
+use std::error::Error;
+
+use super::{grid::Grid, point::Point};
+
+impl<T: Clone> Grid<T> {
+    /// Copy rectangular window starting at top left point into the new grid
+    pub fn crop(
+        &self,
+        top_left: &Point,
+        rows: usize,
+        cols: usize,
+    ) -> Result<Grid<T>, Box<dyn Error>> {
+        if rows == 0 || cols == 0 {
+            return Err(format!("Cropped window {rows}x{cols} is empty").into());
+        }
+
+        let bottom_right = Point::new(
+            top_left.x + cols as isize - 1,
+            top_left.y + rows as isize - 1,
+        );
+        if !self.is_point_in_grid(top_left) || !self.is_point_in_grid(&bottom_right) {
+            return Err(format!(
+                "Window {rows}x{cols} at {top_left:?} is not in the grid {}x{}",
+                self.rows(),
+                self.cols()
+            )
+            .into());
+        }
+
+        Grid::new_with(rows, cols, |p| {
+            self[Point::new(top_left.x + p.x, top_left.y + p.y)].clone()
+        })
+    }
+
+    /// Surround the grid with border of the given width filled with value
+    pub fn pad(&self, border: usize, value: T) -> Grid<T> {
+        let offset = border as isize;
+
+        Grid::new_with(
+            self.rows() + 2 * border,
+            self.cols() + 2 * border,
+            |p| match self.get(&Point::new(p.x - offset, p.y - offset)) {
+                Some(cell) => cell.clone(),
+                None => value.clone(),
+            },
+        )
+        .expect("Padded grid has always valid size")
+    }
+
+    /// Copy other grid into this grid, top left corner of the other grid is placed at offset
+    pub fn paste(&mut self, other: &Grid<T>, offset: &Point) -> Result<(), Box<dyn Error>> {
+        let bottom_right = Point::new(
+            offset.x + other.cols() as isize - 1,
+            offset.y + other.rows() as isize - 1,
+        );
+        if !self.is_point_in_grid(offset) || !self.is_point_in_grid(&bottom_right) {
+            return Err(format!(
+                "Grid {}x{} at {offset:?} does not fit into the grid {}x{}",
+                other.rows(),
+                other.cols(),
+                self.rows(),
+                self.cols()
+            )
+            .into());
+        }
+
+        for index in 0..other.rows() * other.cols() {
+            let point = other.point_of(index);
+            self[Point::new(offset.x + point.x, offset.y + point.y)] = other[index].clone();
+        }
+
+        Ok(())
+    }
+
+    /// Join 2D arrangement of tiles into one grid. All tiles in the same row of tiles must have the
+    /// same number of rows, all tiles in the same column of tiles must have the same number of
+    /// columns.
+    pub fn stitch(tiles: &[Vec<Grid<T>>]) -> Result<Grid<T>, Box<dyn Error>> {
+        if tiles.is_empty() || tiles[0].is_empty() {
+            return Err("No tiles to stitch".into());
+        }
+
+        let widths = tiles[0].iter().map(Grid::cols).collect::<Vec<_>>();
+        let mut data = Vec::new();
+
+        for (i, tile_row) in tiles.iter().enumerate() {
+            if tile_row.len() != widths.len() {
+                return Err(format!(
+                    "Row of tiles {i} has {} tiles, expected {}",
+                    tile_row.len(),
+                    widths.len()
+                )
+                .into());
+            }
+
+            let height = tile_row[0].rows();
+            for (j, tile) in tile_row.iter().enumerate() {
+                if tile.rows() != height || tile.cols() != widths[j] {
+                    return Err(format!(
+                        "Tile [{i}][{j}] has size {}x{}, expected {height}x{}",
+                        tile.rows(),
+                        tile.cols(),
+                        widths[j]
+                    )
+                    .into());
+                }
+            }
+
+            for y in 0..height {
+                data.push(
+                    tile_row
+                        .iter()
+                        .flat_map(|tile| {
+                            (0..tile.cols())
+                                .map(move |x| tile[Point::new(x as isize, y as isize)].clone())
+                        })
+                        .collect::<Vec<_>>(),
+                );
+            }
+        }
+
+        Grid::new(data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build_grid(lines: &[&str]) -> Grid {
+        Grid::new(lines.iter().map(|line| line.chars().collect()).collect()).unwrap()
+    }
+
+    #[test]
+    fn test_crop() {
+        let grid = build_grid(&["abcd", "efgh", "ijkl"]);
+
+        let cropped = grid.crop(&Point::new(1, 1), 2, 3).unwrap();
+        assert!(cropped.equals(&["fgh", "jkl"]));
+
+        assert_eq!(grid.crop(&Point::new(0, 0), 3, 4).unwrap(), grid);
+    }
+
+    #[test]
+    fn test_crop_out_of_bounds() {
+        let grid = build_grid(&["abcd", "efgh", "ijkl"]);
+
+        assert!(grid.crop(&Point::new(2, 1), 2, 3).is_err());
+        assert!(grid.crop(&Point::new(-1, 0), 1, 1).is_err());
+        assert!(grid.crop(&Point::new(0, 0), 0, 1).is_err());
+    }
+
+    #[test]
+    fn test_pad() {
+        let grid = build_grid(&["ab", "cd"]);
+
+        assert!(grid.pad(1, '.').equals(&["....", ".ab.", ".cd.", "...."]));
+        assert_eq!(grid.pad(0, '.'), grid);
+        assert_eq!(
+            grid.pad(2, '.').crop(&Point::new(2, 2), 2, 2).unwrap(),
+            grid
+        );
+    }
+
+    #[test]
+    fn test_paste() {
+        let mut grid = build_grid(&["....", "....", "...."]);
+
+        grid.paste(&build_grid(&["ab", "cd"]), &Point::new(2, 1))
+            .unwrap();
+        assert!(grid.equals(&["....", "..ab", "..cd"]));
+
+        assert!(
+            grid.paste(&build_grid(&["ab", "cd"]), &Point::new(3, 1))
+                .is_err()
+        );
+        assert!(grid.equals(&["....", "..ab", "..cd"]));
+    }
+
+    #[test]
+    fn test_stitch() {
+        let tiles = vec![
+            vec![build_grid(&["ab", "cd"]), build_grid(&["e", "f"])],
+            vec![build_grid(&["gh"]), build_grid(&["i"])],
+        ];
+
+        let grid = Grid::stitch(&tiles).unwrap();
+        assert!(grid.equals(&["abe", "cdf", "ghi"]));
+    }
+
+    #[test]
+    fn test_stitch_invalid() {
+        assert!(Grid::<char>::stitch(&[]).is_err());
+
+        let tiles = vec![
+            vec![build_grid(&["ab", "cd"]), build_grid(&["e", "f"])],
+            vec![build_grid(&["g"]), build_grid(&["i"])],
+        ];
+        assert!(Grid::stitch(&tiles).is_err());
+
+        let tiles = vec![
+            vec![build_grid(&["ab"]), build_grid(&["e"])],
+            vec![build_grid(&["gh"])],
+        ];
+        assert!(Grid::stitch(&tiles).is_err());
+    }
+
+    #[test]
+    fn test_stitch_cropped_tiles() {
+        // Remove borders of the tiles before joining them
+        let tile = build_grid(&["####", "#ab#", "#cd#", "####"]);
+        let inner = tile.crop(&Point::new(1, 1), 2, 2).unwrap();
+
+        let grid = Grid::stitch(&[
+            vec![inner.clone(), inner.clone()],
+            vec![inner.clone(), inner],
+        ])
+        .unwrap();
+        assert!(grid.equals(&["abab", "cdcd", "abab", "cdcd"]));
+    }
+}
@@ -0,0 +1,315 @@
+use std::{
+    error::Error,
+    fmt::Write as _,
+    fs,
+    io::{self, Write},
+    path::Path,
+    thread,
+    time::Duration,
+};
+
+use super::{grid::Grid, point::Point, render::Renderer};
+
+/// Escape sequence which resets colors to the terminal defaults
+pub const RESET: &str = "\x1b[0m";
+
+// Escape sequence which clears the screen from the cursor to the end
+const CLEAR_BELOW: &str = "\x1b[J";
+
+// Recorded frames are separated by empty line in the file, frame itself has no empty lines
+const FRAME_SEPARATOR: &str = "\n\n";
+
+/// Foreground color of the cell
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Color {
+    Black,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+    Gray,
+    Rgb(u8, u8, u8),
+}
+
+impl Color {
+    /// ANSI escape sequence which switches foreground to the color
+    pub fn escape_code(&self) -> String {
+        match self {
+            Color::Black => "\x1b[30m".to_string(),
+            Color::Red => "\x1b[31m".to_string(),
+            Color::Green => "\x1b[32m".to_string(),
+            Color::Yellow => "\x1b[33m".to_string(),
+            Color::Blue => "\x1b[34m".to_string(),
+            Color::Magenta => "\x1b[35m".to_string(),
+            Color::Cyan => "\x1b[36m".to_string(),
+            Color::White => "\x1b[37m".to_string(),
+            Color::Gray => "\x1b[90m".to_string(),
+            Color::Rgb(r, g, b) => format!("\x1b[38;2;{r};{g};{b}m"),
+        }
+    }
+
+    /// Red, green and blue components of the color, e.g. for image export
+    pub fn rgb(&self) -> [u8; 3] {
+        match self {
+            Color::Black => [0, 0, 0],
+            Color::Red => [205, 0, 0],
+            Color::Green => [0, 205, 0],
+            Color::Yellow => [205, 205, 0],
+            Color::Blue => [0, 0, 238],
+            Color::Magenta => [205, 0, 205],
+            Color::Cyan => [0, 205, 205],
+            Color::White => [255, 255, 255],
+            Color::Gray => [127, 127, 127],
+            Color::Rgb(r, g, b) => [*r, *g, *b],
+        }
+    }
+}
+
+type ColorRule = Box<dyn Fn(&Point, char) -> Option<Color>>;
+
+/// Draws grid states to the terminal with colors. Every frame overwrites the previous one, so
+/// that a simulation can be watched step by step. Drawn frames can be recorded and replayed.
+#[derive(Default)]
+pub struct Terminal {
+    rules: Vec<ColorRule>,
+    delay: Duration,
+    recording: bool,
+    frames: Vec<String>,
+    lines_drawn: usize,
+}
+
+impl Terminal {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Wait after every drawn frame
+    pub fn delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    /// Draw all cells containing the character in the color
+    pub fn color_char(self, c: char, color: Color) -> Self {
+        self.color_rule(move |_, cell| (cell == c).then_some(color))
+    }
+
+    /// Add color rule for the cells, rules are checked in order of adding and the first color
+    /// returned is used. Cells without color are drawn in default terminal color.
+    pub fn color_rule<F>(mut self, rule: F) -> Self
+    where
+        F: Fn(&Point, char) -> Option<Color> + 'static,
+    {
+        self.rules.push(Box::new(rule));
+        self
+    }
+
+    /// Keep plain text of all drawn frames
+    pub fn record(mut self) -> Self {
+        self.recording = true;
+        self
+    }
+
+    /// Recorded frames
+    pub fn frames(&self) -> &[String] {
+        &self.frames
+    }
+
+    /// Color of the cell, None for default color
+    pub fn color_of(&self, point: &Point, c: char) -> Option<Color> {
+        self.rules.iter().find_map(|rule| rule(point, c))
+    }
+
+    /// Draw grid over the previous frame to the console
+    pub fn show(&mut self, grid: &Grid) -> io::Result<()> {
+        self.draw(&mut io::stdout().lock(), &Renderer::new(grid))
+    }
+
+    /// Draw rendered grid (including its layers) over the previous frame to the stream
+    pub fn draw<W: Write>(&mut self, out: &mut W, renderer: &Renderer) -> io::Result<()> {
+        let frame = renderer.to_string();
+        self.draw_frame(out, &frame)?;
+
+        if self.recording {
+            self.frames.push(frame);
+        }
+        Ok(())
+    }
+
+    /// Forget the previous frame, next frame is drawn below the current output
+    pub fn reset(&mut self) {
+        self.lines_drawn = 0;
+    }
+
+    /// Colored text of the frame without cursor movement
+    pub fn colorize(&self, frame: &str) -> String {
+        let mut text = String::with_capacity(frame.len() * 2);
+
+        for (y, line) in frame.lines().enumerate() {
+            let mut current = None;
+
+            for (x, c) in line.chars().enumerate() {
+                let color = self.color_of(&Point::new(x as isize, y as isize), c);
+
+                if color != current {
+                    match color {
+                        Some(color) => text.push_str(&color.escape_code()),
+                        None => text.push_str(RESET),
+                    }
+                    current = color;
+                }
+                text.push(c);
+            }
+
+            if current.is_some() {
+                text.push_str(RESET);
+            }
+            text.push('\n');
+        }
+
+        text
+    }
+
+    /// Save recorded frames to the file
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), Box<dyn Error>> {
+        let path = path.as_ref();
+
+        fs::write(path, self.frames.join(FRAME_SEPARATOR))
+            .map_err(|err| format!("Failed to save frames to '{}' [{err}]", path.display()).into())
+    }
+
+    /// Load frames saved by save()
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Vec<String>, Box<dyn Error>> {
+        let path = path.as_ref();
+        let content = fs::read_to_string(path)
+            .map_err(|err| format!("Failed to load frames from '{}' [{err}]", path.display()))?;
+
+        Ok(content
+            .split(FRAME_SEPARATOR)
+            .filter(|frame| !frame.is_empty())
+            .map(String::from)
+            .collect())
+    }
+
+    /// Draw frames one after another with current colors and delay
+    pub fn replay<W: Write>(&mut self, out: &mut W, frames: &[String]) -> io::Result<()> {
+        for frame in frames {
+            self.draw_frame(out, frame)?;
+        }
+        Ok(())
+    }
+
+    fn draw_frame<W: Write>(&mut self, out: &mut W, frame: &str) -> io::Result<()> {
+        let mut text = String::new();
+
+        // Move cursor to the first line of the previous frame and clear it, so that nothing is
+        // left over when the new frame is shorter or narrower
+        if self.lines_drawn > 0 {
+            let _ = write!(text, "\x1b[{}A\r{CLEAR_BELOW}", self.lines_drawn);
+        }
+        text.push_str(&self.colorize(frame));
+
+        out.write_all(text.as_bytes())?;
+        out.flush()?;
+        self.lines_drawn = frame.lines().count();
+
+        if !self.delay.is_zero() {
+            thread::sleep(self.delay);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+
+    fn build_grid() -> Grid {
+        Grid::new(vec![vec!['#', '.', '.'], vec!['.', '.', '#']]).unwrap()
+    }
+
+    #[test]
+    fn test_escape_code() {
+        assert_eq!(Color::Red.escape_code(), "\x1b[31m");
+        assert_eq!(Color::Gray.escape_code(), "\x1b[90m");
+        assert_eq!(Color::Rgb(1, 20, 255).escape_code(), "\x1b[38;2;1;20;255m");
+    }
+
+    #[test]
+    fn test_colorize() {
+        let terminal = Terminal::new()
+            .color_rule(|p, _| (*p == Point::new(2, 1)).then_some(Color::Green))
+            .color_char('#', Color::Red);
+
+        assert_eq!(
+            terminal.colorize("##.\n..#"),
+            "\x1b[31m##\x1b[0m.\n..\x1b[32m#\x1b[0m\n"
+        );
+        assert_eq!(Terminal::new().colorize("#.\n.#"), "#.\n.#\n");
+    }
+
+    #[test]
+    fn test_draw_in_place() {
+        let mut terminal = Terminal::new().color_char('#', Color::Blue);
+        let grid = build_grid();
+        let mut out = Vec::new();
+
+        terminal.draw(&mut out, &Renderer::new(&grid)).unwrap();
+        terminal.draw(&mut out, &Renderer::new(&grid)).unwrap();
+
+        let text = String::from_utf8(out).unwrap();
+        let frame = "\x1b[34m#\x1b[0m..\n..\x1b[34m#\x1b[0m\n";
+        assert_eq!(text, format!("{frame}\x1b[2A\r\x1b[J{frame}"));
+
+        // Shorter frame replaces whole previous frame
+        let mut out = Vec::new();
+        let small = Grid::new(vec![vec!['.']]).unwrap();
+        terminal.draw(&mut out, &Renderer::new(&small)).unwrap();
+        assert_eq!(String::from_utf8(out).unwrap(), "\x1b[2A\r\x1b[J.\n");
+
+        terminal.reset();
+        let mut out = Vec::new();
+        terminal.draw(&mut out, &Renderer::new(&grid)).unwrap();
+        assert_eq!(String::from_utf8(out).unwrap(), frame);
+    }
+
+    #[test]
+    fn test_record_and_replay() {
+        let grid = build_grid();
+        let robot = HashSet::from([Point::new(1, 0)]);
+        let mut terminal = Terminal::new().record();
+        let mut out = Vec::new();
+
+        terminal.draw(&mut out, &Renderer::new(&grid)).unwrap();
+        terminal
+            .draw(&mut out, &Renderer::new(&grid).layer(&robot, '@'))
+            .unwrap();
+        assert_eq!(terminal.frames(), &["#..\n..#", "#@.\n..#"]);
+
+        let path = std::env::temp_dir().join(format!("puzzler_frames_{}.txt", std::process::id()));
+        terminal.save(&path).unwrap();
+        let frames = Terminal::load(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(frames, terminal.frames());
+
+        let mut replayed = Vec::new();
+        Terminal::new()
+            .color_char('@', Color::Yellow)
+            .replay(&mut replayed, &frames)
+            .unwrap();
+        assert_eq!(
+            String::from_utf8(replayed).unwrap(),
+            "#..\n..#\n\x1b[2A\r\x1b[J#\x1b[33m@\x1b[0m.\n..#\n"
+        );
+    }
+
+    #[test]
+    fn test_load_missing_file() {
+        assert!(Terminal::load("/nonexistent/puzzler/frames.txt").is_err());
+    }
+}
@@ -0,0 +1,214 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    ops::Index,
+};
+
+use super::{direction::Direction, grid::Grid, point::Point};
+
+/// View of the grid repeated infinitely in all directions. Any point, including negative or huge
+/// coordinates, resolves to the point of the base grid (tile).
+#[derive(Debug)]
+pub struct TiledGrid<'a, T> {
+    grid: &'a Grid<T>,
+}
+
+impl<'a, T> TiledGrid<'a, T> {
+    pub fn new(grid: &'a Grid<T>) -> Self {
+        Self { grid }
+    }
+
+    /// Base grid which is repeated
+    pub fn grid(&self) -> &'a Grid<T> {
+        self.grid
+    }
+
+    /// Value at any point
+    pub fn get(&self, point: &Point) -> &'a T {
+        &self.grid[self.local_point(point)]
+    }
+
+    /// Point within the base grid
+    pub fn local_point(&self, point: &Point) -> Point {
+        self.grid.wrap_point(point)
+    }
+
+    /// Coordinates of the tile copy the point lies in, base grid is tile (0, 0)
+    pub fn tile_of(&self, point: &Point) -> Point {
+        Point {
+            x: point.x.div_euclid(self.grid.cols() as isize),
+            y: point.y.div_euclid(self.grid.rows() as isize),
+        }
+    }
+
+    /// Neighbors in all directions, every point has all neighbors in the infinite grid
+    pub fn neighbors(&self, point: &Point, directions: &[Direction]) -> Vec<(Point, Direction)> {
+        self.neighbors_if(point, directions, |_, _| true)
+    }
+
+    pub fn neighbors_if<F>(
+        &self,
+        point: &Point,
+        directions: &[Direction],
+        func: F,
+    ) -> Vec<(Point, Direction)>
+    where
+        F: Fn(&Point, &Direction) -> bool,
+    {
+        directions
+            .iter()
+            .map(|direction| (point.neighbor(direction), *direction))
+            .filter(|(neighbor, direction)| func(neighbor, direction))
+            .collect::<Vec<_>>()
+    }
+
+    /// Compute distances from all start points to every point reachable within max_distance
+    /// steps. Step is possible only if passable(from, to) is true, points are not wrapped.
+    pub fn bfs<F>(
+        &self,
+        starts: &[Point],
+        directions: &[Direction],
+        passable: F,
+        max_distance: usize,
+    ) -> HashMap<Point, usize>
+    where
+        F: Fn(&Point, &Point) -> bool,
+    {
+        let mut distances = HashMap::new();
+        let mut queue = VecDeque::new();
+
+        for start in starts {
+            if !distances.contains_key(start) {
+                distances.insert(*start, 0);
+                queue.push_back((*start, 0));
+            }
+        }
+
+        while let Some((point, distance)) = queue.pop_front() {
+            if distance == max_distance {
+                continue;
+            }
+
+            for (neighbor, _) in self.neighbors(&point, directions) {
+                if !distances.contains_key(&neighbor) && passable(&point, &neighbor) {
+                    distances.insert(neighbor, distance + 1);
+                    queue.push_back((neighbor, distance + 1));
+                }
+            }
+        }
+
+        distances
+    }
+}
+
+impl<T> Index<Point> for TiledGrid<'_, T> {
+    type Output = T;
+
+    fn index(&self, index: Point) -> &Self::Output {
+        self.get(&index)
+    }
+}
+
+impl<T> Grid<T> {
+    /// Infinite view of the grid repeated in all directions
+    pub fn tiled(&self) -> TiledGrid<'_, T> {
+        TiledGrid::new(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parsers::parser::Parser;
+
+    use super::*;
+
+    fn build_garden() -> Grid {
+        let lines = [
+            "...........",
+            ".....###.#.",
+            ".###.##..#.",
+            "..#.#...#..",
+            "....#.#....",
+            ".##..S####.",
+            ".##..#...#.",
+            ".......##..",
+            ".##.#.####.",
+            ".##..##.##.",
+            "...........",
+        ];
+
+        Parser::parse_lines_to_grid_str(&lines).unwrap()
+    }
+
+    // Number of points where the walker can end after exactly the given number of steps
+    fn reachable(grid: &Grid, steps: usize) -> usize {
+        let tiled = grid.tiled();
+        let start = grid.get_value('S')[0];
+
+        tiled
+            .bfs(
+                &[start],
+                &Direction::CARDINAL,
+                |_, to| tiled[*to] != '#',
+                steps,
+            )
+            .values()
+            .filter(|distance| *distance % 2 == steps % 2)
+            .count()
+    }
+
+    #[test]
+    fn test_get() {
+        let grid = Grid::new(vec![vec!['a', 'b', 'c'], vec!['d', 'e', 'f']]).unwrap();
+        let tiled = grid.tiled();
+
+        assert_eq!(tiled[Point::new(0, 0)], 'a');
+        assert_eq!(tiled[Point::new(-1, -1)], 'f');
+        assert_eq!(*tiled.get(&Point::new(3_000_000_001, 26_501_365)), 'e');
+        assert_eq!(tiled.local_point(&Point::new(-4, 2)), Point::new(2, 0));
+    }
+
+    #[test]
+    fn test_tile_of() {
+        let grid = Grid::new(vec![vec!['a', 'b', 'c'], vec!['d', 'e', 'f']]).unwrap();
+        let tiled = grid.tiled();
+
+        assert_eq!(tiled.tile_of(&Point::new(2, 1)), Point::new(0, 0));
+        assert_eq!(tiled.tile_of(&Point::new(3, 2)), Point::new(1, 1));
+        assert_eq!(tiled.tile_of(&Point::new(-1, 0)), Point::new(-1, 0));
+        assert_eq!(tiled.tile_of(&Point::new(-3, -3)), Point::new(-1, -2));
+        assert_eq!(tiled.tile_of(&Point::new(-4, 0)), Point::new(-2, 0));
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = Grid::new(vec![vec!['a', 'b'], vec!['c', 'd']]).unwrap();
+        let tiled = grid.tiled();
+
+        assert_eq!(
+            tiled.neighbors(&Point::new(0, 0), &[Direction::North, Direction::West]),
+            vec![
+                (Point::new(0, -1), Direction::North),
+                (Point::new(-1, 0), Direction::West)
+            ]
+        );
+        assert_eq!(
+            tiled.neighbors_if(&Point::new(0, 0), &Direction::CARDINAL, |p, _| {
+                tiled[*p] == 'b'
+            }),
+            vec![
+                (Point::new(1, 0), Direction::East),
+                (Point::new(-1, 0), Direction::West)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_bfs() {
+        let grid = build_garden();
+
+        assert_eq!(reachable(&grid, 6), 16);
+        assert_eq!(reachable(&grid, 10), 50);
+        assert_eq!(reachable(&grid, 50), 1594);
+        assert_eq!(reachable(&grid, 100), 6536);
+    }
+}
@@ -0,0 +1,133 @@
+use super::{grid::Grid, point::Point};
+
+impl<T: Clone> Grid<T> {
+    /// Swap rows and columns, i.e. mirror the grid along its main diagonal
+    pub fn transpose(&self) -> Grid<T> {
+        self.transformed(self.cols(), self.rows(), |p| Point::new(p.y, p.x))
+    }
+
+    /// Rotate the grid by 90 degrees clockwise
+    pub fn rotate_90(&self) -> Grid<T> {
+        let last_row = self.rows() as isize - 1;
+        self.transformed(self.cols(), self.rows(), |p| {
+            Point::new(p.y, last_row - p.x)
+        })
+    }
+
+    /// Rotate the grid by 180 degrees
+    pub fn rotate_180(&self) -> Grid<T> {
+        let (last_row, last_col) = (self.rows() as isize - 1, self.cols() as isize - 1);
+        self.transformed(self.rows(), self.cols(), |p| {
+            Point::new(last_col - p.x, last_row - p.y)
+        })
+    }
+
+    /// Rotate the grid by 270 degrees clockwise, i.e. 90 degrees counterclockwise
+    pub fn rotate_270(&self) -> Grid<T> {
+        let last_col = self.cols() as isize - 1;
+        self.transformed(self.cols(), self.rows(), |p| {
+            Point::new(last_col - p.y, p.x)
+        })
+    }
+
+    /// Mirror the grid left to right
+    pub fn flip_horizontal(&self) -> Grid<T> {
+        let last_col = self.cols() as isize - 1;
+        self.transformed(self.rows(), self.cols(), |p| {
+            Point::new(last_col - p.x, p.y)
+        })
+    }
+
+    /// Mirror the grid top to bottom
+    pub fn flip_vertical(&self) -> Grid<T> {
+        let last_row = self.rows() as isize - 1;
+        self.transformed(self.rows(), self.cols(), |p| {
+            Point::new(p.x, last_row - p.y)
+        })
+    }
+
+    /// All 8 orientations of the grid: 4 rotations (0, 90, 180 and 270 degrees clockwise)
+    /// followed by 4 rotations of the horizontally flipped grid
+    pub fn orientations(&self) -> impl Iterator<Item = Grid<T>> + '_ {
+        (0..8).map(|i| {
+            let grid = match i < 4 {
+                true => self.clone(),
+                false => self.flip_horizontal(),
+            };
+
+            match i % 4 {
+                0 => grid,
+                1 => grid.rotate_90(),
+                2 => grid.rotate_180(),
+                _ => grid.rotate_270(),
+            }
+        })
+    }
+
+    // Create grid of the given size, value of every point is taken from the source point
+    fn transformed<F>(&self, rows: usize, cols: usize, source: F) -> Grid<T>
+    where
+        F: Fn(Point) -> Point,
+    {
+        Grid::new_with(rows, cols, |point| self[source(point)].clone())
+            .expect("Transformed grid has always valid size")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+
+    fn build_grid() -> Grid {
+        Grid::new(vec![vec!['a', 'b', 'c'], vec!['d', 'e', 'f']]).unwrap()
+    }
+
+    #[test]
+    fn test_transpose() {
+        let grid = build_grid().transpose();
+
+        assert_eq!(grid.rows(), 3);
+        assert_eq!(grid.cols(), 2);
+        assert!(grid.equals(&["ad", "be", "cf"]));
+        assert_eq!(grid.transpose(), build_grid());
+    }
+
+    #[test]
+    fn test_rotate() {
+        let grid = build_grid();
+
+        assert!(grid.rotate_90().equals(&["da", "eb", "fc"]));
+        assert!(grid.rotate_180().equals(&["fed", "cba"]));
+        assert!(grid.rotate_270().equals(&["cf", "be", "ad"]));
+
+        assert_eq!(grid.rotate_90().rotate_90(), grid.rotate_180());
+        assert_eq!(grid.rotate_90().rotate_270(), grid);
+    }
+
+    #[test]
+    fn test_flip() {
+        let grid = build_grid();
+
+        assert!(grid.flip_horizontal().equals(&["cba", "fed"]));
+        assert!(grid.flip_vertical().equals(&["def", "abc"]));
+        assert_eq!(grid.flip_horizontal().flip_vertical(), grid.rotate_180());
+    }
+
+    #[test]
+    fn test_orientations() {
+        let grid = build_grid();
+
+        let orientations = grid.orientations().collect::<Vec<_>>();
+        assert_eq!(orientations.len(), 8);
+        assert_eq!(orientations[0], grid);
+        assert_eq!(orientations[4], grid.flip_horizontal());
+        assert!(orientations.contains(&grid.transpose()));
+        assert!(orientations.contains(&grid.flip_vertical()));
+        assert_eq!(orientations.iter().collect::<HashSet<_>>().len(), 8);
+
+        let symmetric = Grid::new(vec![vec![1, 2], vec![2, 1]]).unwrap();
+        assert_eq!(symmetric.orientations().collect::<HashSet<_>>().len(), 2);
+    }
+}
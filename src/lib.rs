@@ -1,4 +1,9 @@
 pub mod env;
+pub mod graphs;
 pub mod grids;
+pub mod ocr;
 pub mod parsers;
 pub mod puzzler;
+pub mod search;
+pub mod symbols;
+pub mod vm;
@@ -0,0 +1,339 @@
+use std::error::Error;
+
+use crate::grids::{grid::Grid, point::Point};
+
+use super::font::{
+    LARGE_CELL_WIDTH, LARGE_FONT, LARGE_HEIGHT, SMALL_CELL_WIDTH, SMALL_FONT, SMALL_HEIGHT,
+};
+
+// Glyph cut from the image together with its first column
+type Glyph = (usize, Vec<Vec<bool>>);
+
+/// Recognition of answers rendered as block letters (4x6 or 6x10 font)
+pub struct Ocr {}
+
+impl Ocr {
+    /// Recognize letters from text, lit pixels are represented by 'lit' character
+    pub fn recognize_str(text: &str, lit: char) -> Result<String, Box<dyn Error>> {
+        let pixels = text
+            .lines()
+            .map(|line| line.chars().map(|c| c == lit).collect())
+            .collect();
+
+        Self::recognize(pixels)
+    }
+
+    /// Recognize letters from grid, lit pixels are represented by 'lit' character
+    pub fn recognize_grid(grid: &Grid, lit: char) -> Result<String, Box<dyn Error>> {
+        let pixels = (0..grid.rows())
+            .map(|y| {
+                (0..grid.cols())
+                    .map(|x| grid[Point::new(x as isize, y as isize)] == lit)
+                    .collect()
+            })
+            .collect();
+
+        Self::recognize(pixels)
+    }
+
+    /// Recognize letters from lit points, coordinates may start anywhere
+    pub fn recognize_points<'a, I>(points: I) -> Result<String, Box<dyn Error>>
+    where
+        I: IntoIterator<Item = &'a Point>,
+    {
+        let points = points.into_iter().collect::<Vec<_>>();
+        if points.is_empty() {
+            return Err("No points to recognize".into());
+        }
+
+        let min_x = points.iter().map(|p| p.x).min().unwrap_or_default();
+        let max_x = points.iter().map(|p| p.x).max().unwrap_or_default();
+        let min_y = points.iter().map(|p| p.y).min().unwrap_or_default();
+        let max_y = points.iter().map(|p| p.y).max().unwrap_or_default();
+
+        let mut pixels =
+            vec![vec![false; (max_x - min_x + 1) as usize]; (max_y - min_y + 1) as usize];
+        for point in points {
+            pixels[(point.y - min_y) as usize][(point.x - min_x) as usize] = true;
+        }
+
+        Self::recognize(pixels)
+    }
+
+    fn recognize(mut pixels: Vec<Vec<bool>>) -> Result<String, Box<dyn Error>> {
+        // Ignore empty rows around the letters
+        while pixels.last().is_some_and(|row| !row.contains(&true)) {
+            pixels.pop();
+        }
+        let first = pixels
+            .iter()
+            .position(|row| row.contains(&true))
+            .ok_or("No letters to recognize")?;
+        pixels.drain(..first);
+
+        let height = pixels.len();
+        let width = pixels.iter().map(Vec::len).max().unwrap_or_default();
+        for row in pixels.iter_mut() {
+            row.resize(width, false);
+        }
+
+        let (font, cell_width) = match height {
+            SMALL_HEIGHT => (Self::load_font(SMALL_FONT), SMALL_CELL_WIDTH),
+            LARGE_HEIGHT => (Self::load_font(LARGE_FONT), LARGE_CELL_WIDTH),
+            _ => {
+                return Err(format!(
+                    "Unsupported letter height '{height}', expected {SMALL_HEIGHT} or {LARGE_HEIGHT}"
+                )
+                .into());
+            }
+        };
+
+        // Letters are placed in cells of fixed width. If some cell does not match, e.g. the letters
+        // are not aligned to the cells, letters separated by empty columns are tried instead.
+        let cells = Self::split_to_cells(&pixels, width, cell_width);
+        Self::match_glyphs(&font, cells).or_else(|err| {
+            Self::match_glyphs(&font, Self::split_by_empty_cols(&pixels, width)).map_err(|_| err)
+        })
+    }
+
+    // Cut image to cells starting at the first lit column, empty columns of every cell are trimmed
+    // and empty cells are skipped
+    fn split_to_cells(pixels: &[Vec<bool>], width: usize, cell_width: usize) -> Vec<Glyph> {
+        let is_empty_col = |col: usize| pixels.iter().all(|row| !row[col]);
+        let Some(first) = (0..width).find(|col| !is_empty_col(*col)) else {
+            return Vec::new();
+        };
+
+        (first..width)
+            .step_by(cell_width)
+            .filter_map(|cell| {
+                let cols = (cell..(cell + cell_width).min(width))
+                    .filter(|col| !is_empty_col(*col))
+                    .collect::<Vec<_>>();
+                let (start, end) = (*cols.first()?, *cols.last()? + 1);
+
+                Some((start, Self::cut_glyph(pixels, start, end)))
+            })
+            .collect()
+    }
+
+    // Cut image to glyphs separated by empty columns
+    fn split_by_empty_cols(pixels: &[Vec<bool>], width: usize) -> Vec<Glyph> {
+        let is_empty_col = |col: usize| pixels.iter().all(|row| !row[col]);
+
+        let mut glyphs = Vec::new();
+        let mut col = 0;
+
+        while col < width {
+            if is_empty_col(col) {
+                col += 1;
+                continue;
+            }
+
+            let start = col;
+            while col < width && !is_empty_col(col) {
+                col += 1;
+            }
+
+            glyphs.push((start, Self::cut_glyph(pixels, start, col)));
+        }
+
+        glyphs
+    }
+
+    fn cut_glyph(pixels: &[Vec<bool>], start: usize, end: usize) -> Vec<Vec<bool>> {
+        pixels.iter().map(|row| row[start..end].to_vec()).collect()
+    }
+
+    fn match_glyphs(
+        font: &[(char, Vec<Vec<bool>>)],
+        glyphs: Vec<Glyph>,
+    ) -> Result<String, Box<dyn Error>> {
+        glyphs
+            .into_iter()
+            .map(|(start, glyph)| -> Result<char, Box<dyn Error>> {
+                font.iter()
+                    .find(|(_, pattern)| *pattern == glyph)
+                    .map(|(letter, _)| *letter)
+                    .ok_or_else(|| {
+                        format!(
+                            "Unknown glyph at column {start}:\n{}",
+                            Self::glyph_to_string(&glyph)
+                        )
+                        .into()
+                    })
+            })
+            .collect()
+    }
+
+    // Convert font to pixels, empty columns around the glyphs are removed
+    fn load_font<const N: usize>(font: &[(char, [&str; N])]) -> Vec<(char, Vec<Vec<bool>>)> {
+        font.iter()
+            .map(|(letter, rows)| {
+                let pixels = rows
+                    .iter()
+                    .map(|row| row.chars().map(|c| c == '#').collect::<Vec<_>>())
+                    .collect::<Vec<_>>();
+
+                let cols = pixels[0].len();
+                let used = (0..cols)
+                    .filter(|col| pixels.iter().any(|row| row[*col]))
+                    .collect::<Vec<_>>();
+                let (start, end) = (used[0], used[used.len() - 1] + 1);
+
+                let trimmed = pixels
+                    .into_iter()
+                    .map(|row| row[start..end].to_vec())
+                    .collect();
+
+                (*letter, trimmed)
+            })
+            .collect()
+    }
+
+    fn glyph_to_string(glyph: &[Vec<bool>]) -> String {
+        glyph
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|lit| if *lit { '#' } else { '.' })
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use crate::parsers::parser::Parser;
+
+    use super::*;
+
+    const SMALL: &str = "\
+#..#.####.#....#.....##.
+#..#.#....#....#....#..#
+####.###..#....#....#..#
+#..#.#....#....#....#..#
+#..#.#....#....#....#..#
+#..#.####.####.####..##.";
+
+    const LARGE: &str = "\
+#....#..######..#....#
+#....#..#.......#....#
+.#..#...#........#..#.
+.#..#...#........#..#.
+..##....#####.....##..
+..##....#.........##..
+.#..#...#........#..#.
+.#..#...#........#..#.
+#....#..#.......#....#
+#....#..######..#....#";
+
+    #[test]
+    fn test_recognize_str_small() {
+        assert_eq!(Ocr::recognize_str(SMALL, '#').unwrap(), "HELLO");
+    }
+
+    #[test]
+    fn test_recognize_str_large() {
+        assert_eq!(Ocr::recognize_str(LARGE, '#').unwrap(), "XEX");
+    }
+
+    // Render text with small font, every letter is left aligned in the cell
+    fn render_small(text: &str) -> String {
+        (0..SMALL_HEIGHT)
+            .map(|row| {
+                text.chars()
+                    .map(|letter| {
+                        let (_, rows) = SMALL_FONT.iter().find(|(c, _)| *c == letter).unwrap();
+                        format!("{:.<width$}", rows[row], width = SMALL_CELL_WIDTH)
+                    })
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn test_recognize_str_full_width_letter() {
+        let text = render_small("YZA");
+        assert!(text.starts_with("#...#####..##.."), "{text}");
+
+        assert_eq!(Ocr::recognize_str(&text, '#').unwrap(), "YZA");
+        assert_eq!(
+            Ocr::recognize_str(&render_small("PLYJ"), '#').unwrap(),
+            "PLYJ"
+        );
+    }
+
+    #[test]
+    fn test_recognize_str_not_aligned() {
+        // Narrow letter followed by single empty column moves the next letter out of its cell
+        let text = "###.#..#\n.#..#..#\n.#..####\n.#..#..#\n.#..#..#\n###.#..#";
+        assert_eq!(Ocr::recognize_str(text, '#').unwrap(), "IH");
+    }
+
+    #[test]
+    fn test_recognize_str_empty_rows() {
+        let text = format!("........................\n{SMALL}\n");
+        assert_eq!(Ocr::recognize_str(&text, '#').unwrap(), "HELLO");
+    }
+
+    #[test]
+    fn test_recognize_grid() {
+        let lines = SMALL.lines().map(String::from).collect();
+        let grid = Parser::parse_lines_to_grid(lines).unwrap();
+
+        assert_eq!(Ocr::recognize_grid(&grid, '#').unwrap(), "HELLO");
+    }
+
+    #[test]
+    fn test_recognize_points() {
+        let points = SMALL
+            .lines()
+            .enumerate()
+            .flat_map(|(y, line)| {
+                line.chars()
+                    .enumerate()
+                    .filter(|(_, c)| *c == '#')
+                    .map(move |(x, _)| Point::new(x as isize + 100, y as isize - 7))
+            })
+            .collect::<HashSet<_>>();
+
+        assert_eq!(Ocr::recognize_points(&points).unwrap(), "HELLO");
+    }
+
+    #[test]
+    fn test_recognize_unknown_glyph() {
+        let text = "####\n####\n####\n####\n####\n####";
+
+        let err = Ocr::recognize_str(text, '#').err().unwrap();
+        assert!(err.to_string().starts_with("Unknown glyph at column 0"));
+    }
+
+    #[test]
+    fn test_recognize_unsupported_height() {
+        assert!(Ocr::recognize_str("#\n#\n#", '#').is_err());
+        assert!(Ocr::recognize_str("...", '#').is_err());
+    }
+
+    #[test]
+    fn test_font_glyphs() {
+        for (letter, rows) in SMALL_FONT {
+            assert_eq!(
+                Ocr::recognize_str(&rows.join("\n"), '#').unwrap(),
+                letter.to_string()
+            );
+        }
+
+        for (letter, rows) in LARGE_FONT {
+            assert_eq!(
+                Ocr::recognize_str(&rows.join("\n"), '#').unwrap(),
+                letter.to_string()
+            );
+        }
+    }
+}
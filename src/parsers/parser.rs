@@ -1,7 +1,19 @@
 use regex::Regex;
-use std::error::Error;
+use std::{
+    collections::HashMap,
+    error::Error,
+    fmt::Display,
+    hash::Hash,
+    ops::{RangeInclusive, Sub},
+    str::FromStr,
+};
 
-use crate::grids::grid::Grid;
+use crate::{graphs::graph::Graph, grids::grid::Grid};
+
+use super::section::{LineError, Section, Sections};
+
+// Result of parsing of a single line, yielded by the lazy iter_* parsers
+pub type LineResult<T> = Result<T, Box<dyn Error>>;
 
 pub struct Parser {}
 
@@ -25,29 +37,23 @@ impl Parser {
 
     // Parse every line to list of integers
     pub fn parse_lines_to_integers(lines: Vec<String>) -> Result<Vec<Vec<isize>>, Box<dyn Error>> {
-        let regex = Regex::new(r"[+-]?\d+")?;
-        let mut numbers = Vec::new();
-
-        for line in lines {
-            // If line contains non-number report error
-            if line.chars().any(|c| c.is_alphabetic()) {
-                return Err(format!("Line '{line}' contains non-number character(s)").into());
-            }
-
-            // Parse numbers, there can be any number of them
-            let line_numbers = regex
-                .find_iter(line.as_str())
-                .map(|s| -> Result<_, Box<dyn Error>> {
-                    s.as_str().parse::<isize>().map_err(|err| {
-                        format!("Failed to parse '{}' to isize [{err}]", s.as_str()).into()
-                    })
-                })
-                .collect::<Result<Vec<_>, Box<dyn Error>>>()?;
+        Self::iter_lines_to_integers(&lines)?.collect()
+    }
 
-            numbers.push(line_numbers);
-        }
+    // Lazily parse every line to list of integers. Lines are only borrowed, e.g. &[String] or
+    // iterator of &str.
+    pub fn iter_lines_to_integers<I>(
+        lines: I,
+    ) -> Result<impl Iterator<Item = LineResult<Vec<isize>>>, Box<dyn Error>>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        let regex = Regex::new(r"[+-]?\d+")?;
 
-        Ok(numbers)
+        Ok(lines
+            .into_iter()
+            .map(move |line| Self::decode_numbers(&regex, line.as_ref())))
     }
 
     // Parse every line to single unsigned integer
@@ -73,29 +79,40 @@ impl Parser {
     pub fn parse_lines_to_unsigned_integers(
         lines: Vec<String>,
     ) -> Result<Vec<Vec<usize>>, Box<dyn Error>> {
-        let regex = Regex::new(r"\d+")?;
-        let mut numbers = Vec::new();
+        Self::iter_lines_to_unsigned_integers(&lines)?.collect()
+    }
 
-        for line in lines {
-            // If line contains non-number report error
-            if line.chars().any(|c| c.is_alphabetic()) {
-                return Err(format!("Line '{line}' contains non-number character(s)").into());
-            }
+    // Lazily parse every line to list of unsigned integers. Lines are only borrowed.
+    pub fn iter_lines_to_unsigned_integers<I>(
+        lines: I,
+    ) -> Result<impl Iterator<Item = LineResult<Vec<usize>>>, Box<dyn Error>>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        let regex = Regex::new(r"\d+")?;
 
-            // Parse numbers, there can be any number of them
-            let line_numbers = regex
-                .find_iter(line.as_str())
-                .map(|s| -> Result<_, Box<dyn Error>> {
-                    s.as_str().parse::<usize>().map_err(|err| {
-                        format!("Failed to parse '{}' to usize [{err}]", s.as_str()).into()
-                    })
-                })
-                .collect::<Result<Vec<_>, Box<dyn Error>>>()?;
+        Ok(lines
+            .into_iter()
+            .map(move |line| Self::decode_numbers(&regex, line.as_ref())))
+    }
 
-            numbers.push(line_numbers);
+    // Decode all numbers found by regex in the line
+    fn decode_numbers<T>(regex: &Regex, line: &str) -> Result<Vec<T>, Box<dyn Error>>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        // If line contains non-number report error
+        if line.chars().any(|c| c.is_alphabetic()) {
+            return Err(format!("Line '{line}' contains non-number character(s)").into());
         }
 
-        Ok(numbers)
+        // Parse numbers, there can be any number of them
+        regex
+            .find_iter(line)
+            .map(|s| Self::parse_value::<T>(s.as_str()))
+            .collect()
     }
 
     // Parse every line to list of strings separated with pattern
@@ -103,18 +120,23 @@ impl Parser {
         lines: Vec<String>,
         pattern: &str,
     ) -> Result<Vec<Vec<String>>, Box<dyn Error>> {
-        let result = lines
-            .into_iter()
-            .map(|line| {
-                line.trim()
-                    .split(pattern)
-                    .map(str::trim)
-                    .map(String::from)
-                    .collect::<Vec<String>>()
-            })
-            .collect::<Vec<Vec<String>>>();
+        Ok(Self::iter_lines_to_strings(&lines, pattern).collect())
+    }
 
-        Ok(result)
+    // Lazily split every line to list of strings separated with pattern. Lines are only borrowed.
+    pub fn iter_lines_to_strings<I>(lines: I, pattern: &str) -> impl Iterator<Item = Vec<String>>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        lines.into_iter().map(move |line| {
+            line.as_ref()
+                .trim()
+                .split(pattern)
+                .map(str::trim)
+                .map(String::from)
+                .collect::<Vec<String>>()
+        })
     }
 
     // Parse lines with using the provided regex. For evevey found match the 'parsing' function F
@@ -126,36 +148,262 @@ impl Parser {
         func: F,
     ) -> Result<Vec<U>, Box<dyn Error>>
     where
-        F: Fn(Vec<String>) -> Result<U, Box<dyn Error>>, // User decoding function of parameters found in regex
+        F: FnMut(Vec<String>) -> Result<U, Box<dyn Error>>, // User decoding function of parameters found in regex
+    {
+        Self::iter_lines_with_regex(&lines, regex, func)?.collect()
+    }
+
+    // Lazily parse lines with using the provided regex, see parse_lines_with_regex(). Lines are
+    // only borrowed.
+    pub fn iter_lines_with_regex<I, F, U>(
+        lines: I,
+        regex: &str,
+        mut func: F,
+    ) -> Result<impl Iterator<Item = LineResult<U>>, Box<dyn Error>>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+        F: FnMut(Vec<String>) -> Result<U, Box<dyn Error>>,
     {
         // Instantiate regex, once for all parsed lines
         let re = Regex::new(regex)?;
 
-        let mut decoded = vec![];
+        Ok(lines.into_iter().map(move |line| {
+            // Decode groups with user specific function. If decode fails, report an error.
+            func(Self::capture_groups(&re, line.as_ref())?)
+        }))
+    }
+
+    // Apply regex pattern to the line and return all its capture groups, in case of no match
+    // report an error
+    fn capture_groups(re: &Regex, line: &str) -> Result<Vec<String>, Box<dyn Error>> {
+        let captures = re
+            .captures(line)
+            .ok_or_else(|| -> Box<dyn Error> { format!("Failed to parse line '{line}'").into() })?;
 
-        // Go through all lines
-        for line in &lines {
-            // Apply regex pattern, in case of no match report an error
-            let captures = re.captures(line).ok_or_else(|| -> Box<dyn Error> {
-                format!("Failed to parse line '{line}'").into()
+        captures
+            .iter()
+            .skip(1)
+            .enumerate()
+            .map(|(i, m)| {
+                m.map(|mat| mat.as_str().to_string())
+                    .ok_or_else(|| format!("Missing capture group at index {i}").into())
+            })
+            .collect()
+    }
+
+    // Parse single line to list of values separated with any of the delimiters, e.g. "3,4,3,1,2".
+    // Empty values are reported as an error.
+    pub fn parse_line_to_list<T>(line: &str, delimiters: &[char]) -> Result<Vec<T>, Box<dyn Error>>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        line.trim()
+            .split(delimiters)
+            .enumerate()
+            .map(|(index, token)| match token.trim().is_empty() {
+                true => Err(format!("Empty value at position {} in '{line}'", index + 1).into()),
+                false => Self::parse_value(token),
+            })
+            .collect()
+    }
+
+    // Parse single line to list of values separated with any number of whitespaces
+    pub fn parse_line_to_list_whitespace<T>(line: &str) -> Result<Vec<T>, Box<dyn Error>>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        line.split_whitespace().map(Self::parse_value).collect()
+    }
+
+    // Parse whole input to single list of values separated with any of the delimiters, values
+    // from all lines are concatenated
+    pub fn parse_lines_to_list<T>(
+        lines: Vec<String>,
+        delimiters: &[char],
+    ) -> Result<Vec<T>, Box<dyn Error>>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        let values =
+            Self::parse_lines_with(lines, |line| Self::parse_line_to_list(line, delimiters))?;
+
+        Ok(values.into_iter().flatten().collect())
+    }
+
+    // Parse text to any type implementing FromStr, surrounding whitespaces are ignored
+    pub fn parse_value<T>(text: &str) -> Result<T, Box<dyn Error>>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        text.trim().parse::<T>().map_err(|err| {
+            format!(
+                "Failed to parse '{text}' to {} [{err}]",
+                std::any::type_name::<T>()
+            )
+            .into()
+        })
+    }
+
+    // Parse every line to key and value separated with separator, e.g. "AB -> C". Every key
+    // has to be unique.
+    pub fn parse_lines_to_map<K, V>(
+        lines: Vec<String>,
+        separator: &str,
+    ) -> Result<HashMap<K, V>, Box<dyn Error>>
+    where
+        K: FromStr + Eq + Hash,
+        K::Err: Display,
+        V: FromStr,
+        V::Err: Display,
+    {
+        Self::parse_lines_to_unique_keys(lines, |line| {
+            let (key, value) = Self::split_key_value(line, separator)?;
+            Ok((Self::parse_value(key)?, Self::parse_value(value)?))
+        })
+    }
+
+    // Parse every line to key and list of values, e.g. "name: a, b, c" or "x = (L, R)". Key is
+    // separated with separator, values with value_separator. Brackets enclosing the values
+    // are ignored. Every key has to be unique.
+    pub fn parse_lines_to_multimap<K, V>(
+        lines: Vec<String>,
+        separator: &str,
+        value_separator: &str,
+    ) -> Result<HashMap<K, Vec<V>>, Box<dyn Error>>
+    where
+        K: FromStr + Eq + Hash,
+        K::Err: Display,
+        V: FromStr,
+        V::Err: Display,
+    {
+        Self::parse_lines_to_unique_keys(lines, |line| {
+            let (key, values) = Self::split_key_value(line, separator)?;
+
+            let values = values
+                .trim()
+                .trim_matches(|c| "()[]{}".contains(c))
+                .split(value_separator)
+                .map(str::trim)
+                .filter(|value| !value.is_empty())
+                .map(Self::parse_value)
+                .collect::<Result<Vec<_>, _>>()?;
+
+            Ok((Self::parse_value(key)?, values))
+        })
+    }
+
+    fn split_key_value<'a>(
+        line: &'a str,
+        separator: &str,
+    ) -> Result<(&'a str, &'a str), Box<dyn Error>> {
+        line.split_once(separator)
+            .ok_or_else(|| format!("Separator '{separator}' not found").into())
+    }
+
+    // Collect (key, value) pairs decoded from every line, duplicate keys are reported as error
+    fn parse_lines_to_unique_keys<F, K, V>(
+        lines: Vec<String>,
+        mut func: F,
+    ) -> Result<HashMap<K, V>, Box<dyn Error>>
+    where
+        F: FnMut(&str) -> Result<(K, V), Box<dyn Error>>,
+        K: Eq + Hash,
+    {
+        let mut map = HashMap::with_capacity(lines.len());
+
+        for (index, line) in lines.into_iter().enumerate() {
+            let (key, value) = match func(&line) {
+                Ok(pair) => pair,
+                Err(err) => {
+                    return Err(Box::new(LineError {
+                        index,
+                        line,
+                        message: err.to_string(),
+                    }));
+                }
+            };
+
+            if map.contains_key(&key) {
+                return Err(Box::new(LineError {
+                    index,
+                    line,
+                    message: "Duplicate key".to_string(),
+                }));
+            }
+
+            map.insert(key, value);
+        }
+
+        Ok(map)
+    }
+
+    // Parse range "a-b", "a..=b" or "a..b" (end exclusive) to inclusive range
+    pub fn parse_range<T>(text: &str) -> Result<RangeInclusive<T>, Box<dyn Error>>
+    where
+        T: FromStr + PartialOrd + Sub<Output = T> + From<u8>,
+        T::Err: Display,
+    {
+        if text.contains("..=") {
+            Self::parse_range_with(text, "..=", true)
+        } else if text.contains("..") {
+            Self::parse_range_with(text, "..", false)
+        } else {
+            Self::parse_range_with(text, "-", true)
+        }
+    }
+
+    // Parse range with start and end separated with delimiter to inclusive range. If range is
+    // not inclusive the end is excluded. Start must not be greater than end.
+    pub fn parse_range_with<T>(
+        text: &str,
+        delimiter: &str,
+        inclusive: bool,
+    ) -> Result<RangeInclusive<T>, Box<dyn Error>>
+    where
+        T: FromStr + PartialOrd + Sub<Output = T> + From<u8>,
+        T::Err: Display,
+    {
+        let text = text.trim();
+
+        // Skip first character so that negative start is not confused with '-' delimiter
+        let pos = text
+            .get(1..)
+            .and_then(|rest| rest.find(delimiter))
+            .map(|pos| pos + 1)
+            .ok_or_else(|| -> Box<dyn Error> {
+                format!("Range delimiter '{delimiter}' not found in '{text}'").into()
             })?;
 
-            let groups = captures
-                .iter()
-                .skip(1)
-                .enumerate()
-                .map(|(i, m)| {
-                    m.map(|mat| mat.as_str().to_string())
-                        .ok_or_else(|| format!("Missing capture group at index {i}").into())
-                })
-                .collect::<Result<Vec<_>, Box<dyn Error>>>()?;
+        let start = Self::parse_value::<T>(&text[..pos])?;
+        let end = Self::parse_value::<T>(&text[pos + delimiter.len()..])?;
 
-            // Decode groups with user specific function. If decode fails, report an error.
-            let values = func(groups)?;
-            decoded.push(values);
+        match inclusive {
+            true if start <= end => Ok(start..=end),
+            false if start < end => Ok(start..=end - T::from(1)),
+            false if start == end => Err(format!("Range '{text}' is empty").into()),
+            _ => Err(format!("Range '{text}' has start greater than end").into()),
         }
+    }
 
-        Ok(decoded)
+    // Parse every line to list of ranges separated with pattern, e.g. "10-14,16-20"
+    pub fn parse_lines_to_ranges<T>(
+        lines: Vec<String>,
+        pattern: &str,
+    ) -> Result<Vec<Vec<RangeInclusive<T>>>, Box<dyn Error>>
+    where
+        T: FromStr + PartialOrd + Sub<Output = T> + From<u8>,
+        T::Err: Display,
+    {
+        Self::parse_lines_with(lines, |line| {
+            line.split(pattern)
+                .map(Self::parse_range)
+                .collect::<Result<Vec<_>, _>>()
+        })
     }
 
     // Parse lines to Grid instance
@@ -177,34 +425,231 @@ impl Parser {
         Grid::new(grid)
     }
 
+    // Parse lines to grid of digits, e.g. height maps
+    pub fn parse_lines_to_digit_grid(lines: Vec<String>) -> Result<Grid<u8>, Box<dyn Error>> {
+        Self::parse_lines_to_grid_with(lines, "0123456789", |c| c as u8 - b'0')
+    }
+
+    // Parse lines to grid of custom values. Every character has to be one of the 'allowed'
+    // characters and is converted with the user function. All rows must have the same length.
+    pub fn parse_lines_to_grid_with<F, T>(
+        lines: Vec<String>,
+        allowed: &str,
+        func: F,
+    ) -> Result<Grid<T>, Box<dyn Error>>
+    where
+        F: Fn(char) -> T,
+    {
+        if lines.is_empty() {
+            return Err("Grid is empty".into());
+        }
+
+        let cols = lines[0].chars().count();
+
+        let grid = Self::parse_lines_with(lines, |line| {
+            let row = line
+                .chars()
+                .enumerate()
+                .map(|(col, c)| -> Result<T, Box<dyn Error>> {
+                    match allowed.contains(c) {
+                        true => Ok(func(c)),
+                        false => {
+                            Err(format!("Invalid character '{c}' at column {}", col + 1).into())
+                        }
+                    }
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+
+            match row.len() == cols {
+                true => Ok(row),
+                false => Err(format!("Length is {}, expected {cols}", row.len()).into()),
+            }
+        })?;
+
+        Grid::new(grid)
+    }
+
+    // Parse edge list to graph, every line contains two nodes separated with separator, e.g. "a-b"
+    pub fn parse_lines_to_graph(
+        lines: Vec<String>,
+        separator: &str,
+        directed: bool,
+    ) -> Result<Graph, Box<dyn Error>> {
+        let mut graph = Graph::new();
+
+        Self::parse_lines_with(lines, |line| {
+            let (from, to) = Self::split_key_value(line, separator)?;
+            let (from, to) = (from.trim(), to.trim());
+
+            if from.is_empty() || to.is_empty() {
+                return Err("Missing node name".into());
+            }
+
+            Self::add_graph_edge(&mut graph, from, to, directed);
+            Ok(())
+        })?;
+
+        Ok(graph)
+    }
+
+    // Parse adjacency lines to graph, every line contains node and list of its neighbors, e.g.
+    // "A -> B, C" (separator "->", list separator ",") or "jqt: rhn xhk" (":" and " ")
+    pub fn parse_lines_to_graph_adjacency(
+        lines: Vec<String>,
+        separator: &str,
+        list_separator: &str,
+        directed: bool,
+    ) -> Result<Graph, Box<dyn Error>> {
+        let mut graph = Graph::new();
+
+        Self::parse_lines_with(lines, |line| {
+            let (node, neighbors) = Self::split_key_value(line, separator)?;
+            let node = node.trim();
+
+            if node.is_empty() {
+                return Err("Missing node name".into());
+            }
+
+            graph.add_node(node);
+            for neighbor in neighbors
+                .split(list_separator)
+                .map(str::trim)
+                .filter(|neighbor| !neighbor.is_empty())
+            {
+                Self::add_graph_edge(&mut graph, node, neighbor, directed);
+            }
+            Ok(())
+        })?;
+
+        Ok(graph)
+    }
+
+    // Parse lines to graph with using the provided regex. For every line the function F gets the
+    // regex groups and returns node name, node data and names of its neighbors, e.g.
+    // "Valve AA has flow rate=0; tunnels lead to valves DD, II".
+    pub fn parse_lines_to_graph_with_regex<F, T>(
+        lines: Vec<String>,
+        regex: &str,
+        directed: bool,
+        mut func: F,
+    ) -> Result<Graph<T>, Box<dyn Error>>
+    where
+        F: FnMut(Vec<String>) -> Result<(String, T, Vec<String>), Box<dyn Error>>,
+        T: Default,
+    {
+        let re = Regex::new(regex)?;
+        let mut graph = Graph::new();
+
+        Self::parse_lines_with(lines, |line| {
+            let (name, data, neighbors) = func(Self::capture_groups(&re, line)?)?;
+
+            let id = graph.add_node(&name);
+            *graph.data_mut(id) = data;
+
+            for neighbor in neighbors {
+                Self::add_graph_edge(&mut graph, &name, &neighbor, directed);
+            }
+            Ok(())
+        })?;
+
+        Ok(graph)
+    }
+
+    fn add_graph_edge<T: Default>(graph: &mut Graph<T>, from: &str, to: &str, directed: bool) {
+        match directed {
+            true => graph.add_edge(from, to),
+            false => graph.add_undirected_edge(from, to),
+        };
+    }
+
     // Group lines by empty lines
     pub fn group_lines(lines: Vec<String>) -> Vec<Vec<String>> {
-        let mut groups = Vec::new();
-        let mut group = Vec::new();
+        Self::group_lines_to_sections(lines)
+            .into_iter()
+            .map(|section| section.lines)
+            .collect()
+    }
+
+    // Group lines by empty lines, every group remembers its position in the input
+    pub fn group_lines_to_sections(lines: Vec<String>) -> Vec<Section> {
+        let mut sections = Vec::new();
+        let mut section = Section {
+            start: 0,
+            lines: Vec::new(),
+        };
 
-        for line in lines {
+        for (index, line) in lines.into_iter().enumerate() {
             // Pattern terminates given group
             match line.is_empty() {
                 true => {
                     // End of group
-                    if !group.is_empty() {
-                        groups.push(group.clone());
-                        group.clear();
+                    if !section.lines.is_empty() {
+                        sections.push(section.clone());
+                        section.lines.clear();
                     }
                 }
                 false => {
-                    // Still in the group
-                    group.push(line);
+                    // Still in the group, remember where the group starts
+                    if section.lines.is_empty() {
+                        section.start = index;
+                    }
+                    section.lines.push(line);
                 }
             }
         }
 
         // Add last group if available
-        if !group.is_empty() {
-            groups.push(group);
+        if !section.lines.is_empty() {
+            sections.push(section);
         }
 
-        groups
+        sections
+    }
+
+    // Parse input consisting of blocks separated by empty lines. Every block is described by
+    // a (name, parser) pair, e.g. (("grid", Parser::parse_lines_to_grid), ("moves", ...)).
+    // Result is a tuple with one value per block. Errors name the failed section and line.
+    pub fn parse_sections<S>(lines: Vec<String>, sections: S) -> Result<S::Output, Box<dyn Error>>
+    where
+        S: Sections,
+    {
+        sections.parse(Self::group_lines_to_sections(lines))
+    }
+
+    // Parse every line with the user function. In case of an error the failed line is reported.
+    pub fn parse_lines_with<F, U>(lines: Vec<String>, func: F) -> Result<Vec<U>, Box<dyn Error>>
+    where
+        F: FnMut(&str) -> Result<U, Box<dyn Error>>,
+    {
+        Self::iter_lines_with(&lines, func).collect()
+    }
+
+    // Lazily parse every line with the user function, see parse_lines_with(). Lines are only
+    // borrowed.
+    pub fn iter_lines_with<I, F, U>(lines: I, mut func: F) -> impl Iterator<Item = LineResult<U>>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+        F: FnMut(&str) -> Result<U, Box<dyn Error>>,
+    {
+        lines.into_iter().enumerate().map(move |(index, line)| {
+            let line = line.as_ref();
+
+            func(line).map_err(|err| -> Box<dyn Error> {
+                Box::new(LineError {
+                    index,
+                    line: line.to_string(),
+                    message: err.to_string(),
+                })
+            })
+        })
+    }
+
+    // Lazily group lines by empty lines, groups are borrowed from the lines
+    pub fn iter_groups<S: AsRef<str>>(lines: &[S]) -> impl Iterator<Item = &[S]> {
+        lines
+            .split(|line| line.as_ref().is_empty())
+            .filter(|group| !group.is_empty())
     }
 
     pub fn decode_line_to_unsigned_integer(line: &str, pat: &str) -> Result<usize, Box<dyn Error>> {
@@ -281,6 +726,143 @@ mod tests {
         assert_eq!(groups[1], vec!["Something else".to_string()])
     }
 
+    #[test]
+    fn test_group_lines_to_sections() {
+        let lines = vec![
+            "".to_string(),
+            "1".to_string(),
+            "2".to_string(),
+            "".to_string(),
+            "".to_string(),
+            "3".to_string(),
+        ];
+
+        let sections = Parser::group_lines_to_sections(lines);
+
+        assert_eq!(
+            sections,
+            vec![
+                Section {
+                    start: 1,
+                    lines: vec!["1".to_string(), "2".to_string()]
+                },
+                Section {
+                    start: 5,
+                    lines: vec!["3".to_string()]
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_sections() {
+        let lines = vec![
+            "#..".to_string(),
+            ".#.".to_string(),
+            "".to_string(),
+            "move 1".to_string(),
+            "move 42".to_string(),
+        ];
+
+        let result = Parser::parse_sections(
+            lines,
+            (
+                ("grid", Parser::parse_lines_to_grid),
+                ("moves", |lines| {
+                    Parser::parse_lines_with(lines, |line| {
+                        Parser::decode_line_to_unsigned_integer(line, "move")
+                    })
+                }),
+            ),
+        );
+        assert!(result.is_ok(), "{:?}", result.err());
+
+        let (grid, moves) = result.unwrap();
+        assert!(grid.equals(&["#..", ".#."]));
+        assert_eq!(moves, vec![1, 42]);
+    }
+
+    #[test]
+    fn test_parse_sections_line_error() {
+        let lines = vec![
+            "#..".to_string(),
+            "".to_string(),
+            "move 1".to_string(),
+            "move oops".to_string(),
+        ];
+
+        let result = Parser::parse_sections(
+            lines,
+            (
+                ("grid", Parser::parse_lines_to_grid),
+                ("moves", |lines| {
+                    Parser::parse_lines_with(lines, |line| {
+                        Parser::decode_line_to_unsigned_integer(line, "move")
+                    })
+                }),
+            ),
+        );
+
+        let err = result.err().unwrap().to_string();
+        assert!(
+            err.starts_with("Section 2 'moves', line 4 'move oops'"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn test_parse_sections_grid_error() {
+        let lines = vec![
+            "12".to_string(),
+            "".to_string(),
+            "45".to_string(),
+            "3x".to_string(),
+        ];
+
+        let result = Parser::parse_sections(
+            lines,
+            (
+                ("a", Parser::parse_lines_to_digit_grid),
+                ("b", Parser::parse_lines_to_digit_grid),
+            ),
+        );
+        let err = result.err().unwrap().to_string();
+        assert_eq!(
+            err,
+            "Section 2 'b', line 4 '3x': Invalid character 'x' at column 2"
+        );
+
+        let lines = vec!["78".to_string(), "9".to_string()];
+
+        let result = Parser::parse_sections(lines, (("c", Parser::parse_lines_to_digit_grid),));
+        let err = result.err().unwrap().to_string();
+        assert_eq!(err, "Section 1 'c', line 2 '9': Length is 1, expected 2");
+    }
+
+    #[test]
+    fn test_parse_sections_error() {
+        let lines = vec!["1 2".to_string(), "".to_string(), "x".to_string()];
+
+        let result = Parser::parse_sections(
+            lines,
+            (
+                ("numbers", Parser::parse_lines_to_integers),
+                ("letters", Parser::parse_lines_to_integers),
+            ),
+        );
+
+        let err = result.err().unwrap().to_string();
+        assert!(err.starts_with("Section 2 'letters' (lines 3-3)"), "{err}");
+    }
+
+    #[test]
+    fn test_parse_sections_count_mismatch() {
+        let lines = vec!["1".to_string(), "".to_string(), "2".to_string()];
+
+        let result = Parser::parse_sections(lines, (("numbers", Parser::parse_lines_to_integers),));
+        assert!(result.is_err());
+    }
+
     #[test]
     fn test_parse_lines_with_regex() {
         let lines = vec![
@@ -459,6 +1041,91 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_iter_lines_to_integers() {
+        let lines = ["-1 2", "3", "oops", "4"];
+
+        let mut iter = Parser::iter_lines_to_integers(lines).unwrap();
+        assert_eq!(iter.next().unwrap().unwrap(), vec![-1, 2]);
+        assert_eq!(iter.next().unwrap().unwrap(), vec![3]);
+        assert!(iter.next().unwrap().is_err());
+        assert_eq!(iter.next().unwrap().unwrap(), vec![4]);
+        assert!(iter.next().is_none());
+    }
+
+    #[test]
+    fn test_iter_lines_to_unsigned_integers() {
+        let lines = vec!["1 2".to_string(), "3".to_string()];
+
+        let result = Parser::iter_lines_to_unsigned_integers(&lines)
+            .unwrap()
+            .collect::<Result<Vec<_>, _>>();
+        assert!(result.is_ok(), "{result:?}");
+        assert_eq!(result.unwrap(), vec![vec![1, 2], vec![3]]);
+    }
+
+    #[test]
+    fn test_iter_lines_to_strings() {
+        let text = "a, b\nc";
+
+        let result = Parser::iter_lines_to_strings(text.lines(), ",").collect::<Vec<_>>();
+        assert_eq!(
+            result,
+            vec![
+                vec!["a".to_string(), "b".to_string()],
+                vec!["c".to_string()]
+            ]
+        );
+    }
+
+    #[test]
+    fn test_iter_lines_with_regex() {
+        let lines = ["x=1", "x=2"];
+        let mut count = 0;
+
+        let result = Parser::iter_lines_with_regex(lines, r"^x=(\d+)$", |params| {
+            count += 1;
+            Parser::parse_value::<usize>(&params[0])
+        })
+        .unwrap()
+        .collect::<Result<Vec<_>, _>>();
+
+        assert!(result.is_ok(), "{result:?}");
+        assert_eq!(result.unwrap(), vec![1, 2]);
+        assert_eq!(count, 2);
+    }
+
+    #[test]
+    fn test_iter_lines_with() {
+        let lines = ["1", "x"];
+
+        let result = Parser::iter_lines_with(lines, Parser::parse_value::<usize>)
+            .map(|value| value.map_err(|err| err.to_string()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(result[0], Ok(1));
+        assert!(result[1].as_ref().unwrap_err().starts_with("Line 2 'x'"));
+    }
+
+    #[test]
+    fn test_iter_groups() {
+        let lines = vec![
+            "1".to_string(),
+            "".to_string(),
+            "".to_string(),
+            "2".to_string(),
+            "3".to_string(),
+            "".to_string(),
+        ];
+
+        let groups = Parser::iter_groups(&lines).collect::<Vec<_>>();
+        assert_eq!(groups, vec![&lines[0..1], &lines[3..5]]);
+
+        let borrowed = ["a", "", "b"];
+        let groups = Parser::iter_groups(&borrowed).collect::<Vec<_>>();
+        assert_eq!(groups, vec![&["a"], &["b"]]);
+    }
+
     #[test]
     fn test_parse_lines_to_grid() {
         let lines = vec![
@@ -501,6 +1168,356 @@ mod tests {
         assert_eq!(grid[Point { x: 5, y: 2 }], '#');
     }
 
+    #[test]
+    fn test_parse_lines_to_digit_grid() {
+        let lines = vec!["0123".to_string(), "4567".to_string(), "8900".to_string()];
+
+        let result = Parser::parse_lines_to_digit_grid(lines);
+        assert!(result.is_ok(), "{result:?}");
+        assert_eq!(
+            result.unwrap(),
+            Grid::new(vec![vec![0, 1, 2, 3], vec![4, 5, 6, 7], vec![8, 9, 0, 0]]).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parse_lines_to_digit_grid_invalid_character() {
+        let lines = vec!["0123".to_string(), "45x7".to_string()];
+
+        let result = Parser::parse_lines_to_digit_grid(lines);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_parse_lines_to_digit_grid_invalid_length() {
+        let lines = vec!["0123".to_string(), "456".to_string()];
+
+        let result = Parser::parse_lines_to_digit_grid(lines);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_parse_lines_to_grid_with() {
+        let lines = vec!["#.".to_string(), ".#".to_string()];
+
+        let result = Parser::parse_lines_to_grid_with(lines, "#.", |c| c == '#');
+        assert!(result.is_ok(), "{result:?}");
+        assert_eq!(
+            result.unwrap(),
+            Grid::new(vec![vec![true, false], vec![false, true]]).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parse_value() {
+        assert_eq!(Parser::parse_value::<isize>(" -42 ").unwrap(), -42);
+        assert_eq!(Parser::parse_value::<u8>("7").unwrap(), 7);
+        assert!(Parser::parse_value::<usize>("-7").is_err());
+    }
+
+    #[test]
+    fn test_parse_line_to_list() {
+        let result = Parser::parse_line_to_list::<usize>("3,4,3,1,2", &[',']);
+        assert!(result.is_ok(), "{result:?}");
+        assert_eq!(result.unwrap(), vec![3, 4, 3, 1, 2]);
+
+        let result = Parser::parse_line_to_list::<isize>(" -1, 2;3 ", &[',', ';']);
+        assert!(result.is_ok(), "{result:?}");
+        assert_eq!(result.unwrap(), vec![-1, 2, 3]);
+    }
+
+    #[test]
+    fn test_parse_line_to_list_empty_value() {
+        let result = Parser::parse_line_to_list::<usize>("3,4,,1", &[',']);
+
+        let err = result.err().unwrap().to_string();
+        assert_eq!(err, "Empty value at position 3 in '3,4,,1'");
+        assert!(Parser::parse_line_to_list::<usize>("", &[',']).is_err());
+    }
+
+    #[test]
+    fn test_parse_line_to_list_whitespace() {
+        let result = Parser::parse_line_to_list_whitespace::<usize>("  0 2\t7   0 ");
+        assert!(result.is_ok(), "{result:?}");
+        assert_eq!(result.unwrap(), vec![0, 2, 7, 0]);
+    }
+
+    #[test]
+    fn test_parse_lines_to_list() {
+        let lines = vec!["1,2".to_string(), "3".to_string()];
+
+        let result = Parser::parse_lines_to_list::<u8>(lines, &[',']);
+        assert!(result.is_ok(), "{result:?}");
+        assert_eq!(result.unwrap(), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_parse_lines_to_list_error() {
+        let lines = vec!["1,2".to_string(), "3,x".to_string()];
+
+        let result = Parser::parse_lines_to_list::<u8>(lines, &[',']);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_parse_lines_to_map() {
+        let lines = vec!["AB -> C".to_string(), "CH -> B".to_string()];
+
+        let result = Parser::parse_lines_to_map::<String, char>(lines, "->");
+        assert!(result.is_ok(), "{result:?}");
+        assert_eq!(
+            result.unwrap(),
+            HashMap::from([("AB".to_string(), 'C'), ("CH".to_string(), 'B')])
+        );
+    }
+
+    #[test]
+    fn test_parse_lines_to_map_duplicate_key() {
+        let lines = vec![
+            "AB -> C".to_string(),
+            "CH -> B".to_string(),
+            "AB -> H".to_string(),
+        ];
+
+        let result = Parser::parse_lines_to_map::<String, char>(lines, "->");
+
+        let err = result.err().unwrap().to_string();
+        assert_eq!(err, "Line 3 'AB -> H': Duplicate key");
+    }
+
+    #[test]
+    fn test_parse_lines_to_map_missing_separator() {
+        let lines = vec!["AB -> C".to_string(), "CH B".to_string()];
+
+        let result = Parser::parse_lines_to_map::<String, char>(lines, "->");
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_parse_lines_to_multimap() {
+        let lines = vec![
+            "AAA = (BBB, CCC)".to_string(),
+            "BBB = (DDD, EEE)".to_string(),
+        ];
+
+        let result = Parser::parse_lines_to_multimap::<String, String>(lines, "=", ",");
+        assert!(result.is_ok(), "{result:?}");
+        assert_eq!(
+            result.unwrap(),
+            HashMap::from([
+                (
+                    "AAA".to_string(),
+                    vec!["BBB".to_string(), "CCC".to_string()]
+                ),
+                (
+                    "BBB".to_string(),
+                    vec!["DDD".to_string(), "EEE".to_string()]
+                ),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_parse_lines_to_multimap_numbers() {
+        let lines = vec!["1: 2 3  4".to_string(), "5:".to_string()];
+
+        let result = Parser::parse_lines_to_multimap::<usize, usize>(lines, ":", " ");
+        assert!(result.is_ok(), "{result:?}");
+        assert_eq!(
+            result.unwrap(),
+            HashMap::from([(1, vec![2, 3, 4]), (5, vec![])])
+        );
+    }
+
+    #[test]
+    fn test_parse_lines_to_multimap_duplicate_key() {
+        let lines = vec!["a: b, c".to_string(), "a: d".to_string()];
+
+        let result = Parser::parse_lines_to_multimap::<String, String>(lines, ":", ",");
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_parse_range() {
+        assert_eq!(Parser::parse_range::<usize>("3-5").unwrap(), 3..=5);
+        assert_eq!(Parser::parse_range::<usize>("3..=5").unwrap(), 3..=5);
+        assert_eq!(Parser::parse_range::<usize>("3..5").unwrap(), 3..=4);
+        assert_eq!(Parser::parse_range::<isize>("-5--3").unwrap(), -5..=-3);
+        assert_eq!(Parser::parse_range::<isize>("-5..-3").unwrap(), -5..=-4);
+        assert_eq!(Parser::parse_range::<usize>(" 7-7 ").unwrap(), 7..=7);
+    }
+
+    #[test]
+    fn test_parse_range_error() {
+        let result = Parser::parse_range::<usize>("5-3");
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Range '5-3' has start greater than end"
+        );
+
+        let result = Parser::parse_range::<usize>("5..5");
+        assert_eq!(result.unwrap_err().to_string(), "Range '5..5' is empty");
+        assert!(Parser::parse_range::<usize>("5..3").is_err());
+        assert!(Parser::parse_range::<usize>("5").is_err());
+        assert!(Parser::parse_range::<usize>("a-5").is_err());
+    }
+
+    #[test]
+    fn test_parse_range_with() {
+        assert_eq!(
+            Parser::parse_range_with::<usize>("10 to 20", "to", true).unwrap(),
+            10..=20
+        );
+        assert_eq!(
+            Parser::parse_range_with::<usize>("10:20", ":", false).unwrap(),
+            10..=19
+        );
+    }
+
+    #[test]
+    fn test_parse_lines_to_ranges() {
+        let lines = vec!["2-4,6-8".to_string(), "5-7,7-9".to_string()];
+
+        let result = Parser::parse_lines_to_ranges::<usize>(lines, ",");
+        assert!(result.is_ok(), "{result:?}");
+        assert_eq!(
+            result.unwrap(),
+            vec![vec![2..=4, 6..=8], vec![5..=7, 7..=9]]
+        );
+    }
+
+    #[test]
+    fn test_parse_lines_to_ranges_error() {
+        let lines = vec!["2-4,6-8".to_string(), "7-5,7-9".to_string()];
+
+        let result = Parser::parse_lines_to_ranges::<usize>(lines, ",");
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_parse_lines_to_graph() {
+        let lines = vec![
+            "start-A".to_string(),
+            "A-b".to_string(),
+            "b-end".to_string(),
+        ];
+
+        let result = Parser::parse_lines_to_graph(lines, "-", false);
+        assert!(result.is_ok(), "{:?}", result.err());
+
+        let graph = result.unwrap();
+        assert_eq!(graph.len(), 4);
+        assert_eq!(graph.edge_count(), 6);
+
+        let a = graph.id("A").unwrap();
+        let neighbors = graph
+            .neighbors(a)
+            .iter()
+            .map(|id| graph.name(*id))
+            .collect::<Vec<_>>();
+        assert_eq!(neighbors, vec!["start", "b"]);
+    }
+
+    #[test]
+    fn test_parse_lines_to_graph_error() {
+        let lines = vec!["a-b".to_string(), "c".to_string()];
+
+        let result = Parser::parse_lines_to_graph(lines, "-", false);
+        assert!(result.unwrap_err().to_string().starts_with("Line 2 'c'"));
+
+        let lines = vec!["A:".to_string(), ": B".to_string()];
+        let result = Parser::parse_lines_to_graph_adjacency(lines, ":", " ", true);
+        assert!(result.unwrap_err().to_string().starts_with("Line 2 ': B'"));
+
+        // Edge to 'x' is rejected by the decode function
+        fn decode(params: Vec<String>) -> Result<(String, (), Vec<String>), Box<dyn Error>> {
+            match params[1].as_str() {
+                "x" => Err("Invalid neighbor".into()),
+                _ => Ok((params[0].clone(), (), vec![params[1].clone()])),
+            }
+        }
+
+        let lines = vec!["A -> B".to_string(), "B => A".to_string()];
+        let err = Parser::parse_lines_to_graph_with_regex(lines, r"^(\w) -> (\w)$", true, decode)
+            .unwrap_err();
+        assert!(
+            err.downcast_ref::<LineError>()
+                .is_some_and(|err| err.index == 1)
+        );
+        assert!(err.to_string().starts_with("Line 2 'B => A'"));
+
+        let lines = vec!["A -> B".to_string(), "C -> x".to_string()];
+        let result =
+            Parser::parse_lines_to_graph_with_regex(lines, r"^(\w) -> (\w)$", true, decode);
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Line 2 'C -> x': Invalid neighbor"
+        );
+    }
+
+    #[test]
+    fn test_parse_lines_to_graph_adjacency() {
+        let lines = vec![
+            "A -> B, C".to_string(),
+            "B -> C".to_string(),
+            "D ->".to_string(),
+        ];
+
+        let result = Parser::parse_lines_to_graph_adjacency(lines, "->", ",", true);
+        assert!(result.is_ok(), "{:?}", result.err());
+
+        let graph = result.unwrap();
+        assert_eq!(graph.len(), 4);
+        assert_eq!(graph.neighbors(graph.id("A").unwrap()), &[1, 2]);
+        assert_eq!(graph.neighbors(graph.id("B").unwrap()), &[2]);
+        assert!(graph.neighbors(graph.id("C").unwrap()).is_empty());
+        assert!(graph.neighbors(graph.id("D").unwrap()).is_empty());
+    }
+
+    #[test]
+    fn test_parse_lines_to_graph_adjacency_undirected() {
+        let lines = vec!["jqt: rhn xhk".to_string(), "rhn: xhk".to_string()];
+
+        let graph = Parser::parse_lines_to_graph_adjacency(lines, ":", " ", false).unwrap();
+        assert_eq!(graph.len(), 3);
+        assert_eq!(graph.edge_count(), 6);
+    }
+
+    #[test]
+    fn test_parse_lines_to_graph_with_regex() {
+        let lines = vec![
+            "Valve AA has flow rate=0; tunnels lead to valves DD, BB".to_string(),
+            "Valve BB has flow rate=13; tunnel leads to valve AA".to_string(),
+            "Valve DD has flow rate=20; tunnels lead to valves AA".to_string(),
+        ];
+
+        let result = Parser::parse_lines_to_graph_with_regex(
+            lines,
+            r"^Valve (\w+) has flow rate=(\d+); tunnels? leads? to valves? (.*)$",
+            true,
+            |params| {
+                Ok((
+                    params[0].clone(),
+                    Parser::parse_value::<usize>(&params[1])?,
+                    params[2].split(", ").map(String::from).collect(),
+                ))
+            },
+        );
+        assert!(result.is_ok(), "{:?}", result.err());
+
+        let graph = result.unwrap();
+        let aa = graph.id("AA").unwrap();
+        let bb = graph.id("BB").unwrap();
+        let dd = graph.id("DD").unwrap();
+
+        assert_eq!(graph.len(), 3);
+        assert_eq!(*graph.data(aa), 0);
+        assert_eq!(*graph.data(bb), 13);
+        assert_eq!(*graph.data(dd), 20);
+        assert_eq!(graph.neighbors(aa), &[dd, bb]);
+        assert_eq!(graph.neighbors(bb), &[aa]);
+    }
+
     #[test]
     fn test_decode_line_to_unsigned_integer() {
         let result = Parser::decode_line_to_unsigned_integer("Age: 42", "Age:");
@@ -0,0 +1,106 @@
+use std::{error::Error, fmt::Display};
+
+// Error reported by line oriented parsers. It keeps the index of the failed line relative to the
+// parsed lines, so that callers (e.g. section parsing) can translate it to the input line number.
+#[derive(Debug)]
+pub struct LineError {
+    pub index: usize,
+    pub line: String,
+    pub message: String,
+}
+
+impl Display for LineError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Line {} '{}': {}",
+            self.index + 1,
+            self.line,
+            self.message
+        )
+    }
+}
+
+impl Error for LineError {}
+
+// Block of lines separated from other blocks by empty line(s)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Section {
+    // Index of the first line of the section in the input (0-based)
+    pub start: usize,
+    pub lines: Vec<String>,
+}
+
+// Description of the sections of the input. It is implemented for tuples of (name, parser) pairs,
+// where parser is any function taking lines of the section, e.g. Parser::parse_lines_to_grid.
+pub trait Sections {
+    type Output;
+
+    fn parse(self, sections: Vec<Section>) -> Result<Self::Output, Box<dyn Error>>;
+}
+
+// Parse single section, errors are extended with the section name and input line number(s)
+fn parse_section<F, T>(
+    number: usize,
+    name: &str,
+    section: Section,
+    func: F,
+) -> Result<T, Box<dyn Error>>
+where
+    F: FnOnce(Vec<String>) -> Result<T, Box<dyn Error>>,
+{
+    let first = section.start + 1;
+    let last = section.start + section.lines.len();
+
+    func(section.lines).map_err(|err| -> Box<dyn Error> {
+        match err.downcast_ref::<LineError>() {
+            Some(line_err) => format!(
+                "Section {number} '{name}', line {} '{}': {}",
+                section.start + line_err.index + 1,
+                line_err.line,
+                line_err.message
+            )
+            .into(),
+            None => format!("Section {number} '{name}' (lines {first}-{last}): {err}").into(),
+        }
+    })
+}
+
+macro_rules! impl_sections {
+    ($count:literal; $($func:ident $out:ident $index:tt),+) => {
+        impl<'a, $($func, $out),+> Sections for ($((&'a str, $func),)+)
+        where
+            $($func: FnOnce(Vec<String>) -> Result<$out, Box<dyn Error>>),+
+        {
+            type Output = ($($out,)+);
+
+            fn parse(self, sections: Vec<Section>) -> Result<Self::Output, Box<dyn Error>> {
+                if sections.len() != $count {
+                    return Err(format!(
+                        "Expected {} section(s), found {}",
+                        $count,
+                        sections.len()
+                    )
+                    .into());
+                }
+
+                let mut sections = sections.into_iter();
+
+                Ok(($(
+                    parse_section(
+                        $index + 1,
+                        self.$index.0,
+                        sections.next().ok_or("Missing section")?,
+                        self.$index.1,
+                    )?,
+                )+))
+            }
+        }
+    };
+}
+
+impl_sections!(1; F0 T0 0);
+impl_sections!(2; F0 T0 0, F1 T1 1);
+impl_sections!(3; F0 T0 0, F1 T1 1, F2 T2 2);
+impl_sections!(4; F0 T0 0, F1 T1 1, F2 T2 2, F3 T3 3);
+impl_sections!(5; F0 T0 0, F1 T1 1, F2 T2 2, F3 T3 3, F4 T4 4);
@@ -1,4 +1,4 @@
-use std::{error::Error, fs::File, io::BufRead, io::BufReader, path::PathBuf};
+use std::{error::Error, fs, fs::File, io::BufRead, io::BufReader, path::PathBuf};
 
 // Puzzle which can be solved by Solver
 pub trait Puzzle {
@@ -9,11 +9,23 @@ pub trait Puzzle {
         None
     }
 
-    // Parse the file content for the puzzle. It is typically used in solve_partX() methods
+    // Parse the file content for the puzzle. It is typically used in solve_partX() methods.
     fn parse_content(&mut self, _lines: Vec<String>) -> Result<(), Box<dyn Error>> {
         Ok(())
     }
 
+    // Parse the input file with parse_content_borrowed() instead of parse_content()
+    fn borrows_content(&self) -> bool {
+        false
+    }
+
+    // Parse borrowed file content for the puzzle. Implement this one together with
+    // borrows_content() to parse the lines lazily with Parser::iter_* functions without
+    // allocating every line.
+    fn parse_content_borrowed(&mut self, _lines: &[&str]) -> Result<(), Box<dyn Error>> {
+        Ok(())
+    }
+
     // Solve first part of the puzzle
     fn solve_part1(&mut self) -> Result<String, Box<dyn Error>> {
         Ok("Not solved".into())
@@ -33,6 +45,20 @@ pub trait Puzzle {
     fn parse_input_file(&mut self) -> Result<(), Box<dyn Error>> {
         // Read file
         if let Some(input_file_path) = self.get_input_file_path() {
+            if self.borrows_content() {
+                let content = fs::read_to_string(&input_file_path).map_err(|e| {
+                    format!(
+                        "Failed to read file '{}' [{}]",
+                        input_file_path.display(),
+                        e
+                    )
+                })?;
+
+                // Parse content, lines are only borrowed from the file content
+                let lines = content.lines().collect::<Vec<_>>();
+                return self.parse_content_borrowed(&lines);
+            }
+
             let file = File::open(&input_file_path).map_err(|e| {
                 format!(
                     "Failed to open file '{}' [{}]",
@@ -65,6 +65,10 @@ impl Solver {
 
 #[cfg(test)]
 mod test {
+    use std::path::Path;
+
+    use crate::parsers::parser::Parser;
+
     use super::*;
 
     // Minimalistic test puzzle
@@ -82,6 +86,68 @@ mod test {
         let _solver = Solver::new(Box::new(TestPuzzle {}), 3);
     }
 
+    // Puzzle parsing the input file
+    struct FilePuzzle {
+        path: PathBuf,
+        borrowed: bool,
+        lines: Vec<String>,
+        numbers: Vec<usize>,
+    }
+    impl FilePuzzle {
+        fn new(path: &Path, borrowed: bool) -> Self {
+            Self {
+                path: path.to_path_buf(),
+                borrowed,
+                lines: vec![],
+                numbers: vec![],
+            }
+        }
+    }
+    impl Puzzle for FilePuzzle {
+        fn name(&self) -> &str {
+            "FilePuzzle"
+        }
+
+        fn get_input_file_path(&self) -> Option<PathBuf> {
+            Some(self.path.clone())
+        }
+
+        fn parse_content(&mut self, lines: Vec<String>) -> Result<(), Box<dyn Error>> {
+            self.lines = lines;
+            Ok(())
+        }
+
+        fn borrows_content(&self) -> bool {
+            self.borrowed
+        }
+
+        fn parse_content_borrowed(&mut self, lines: &[&str]) -> Result<(), Box<dyn Error>> {
+            self.numbers = Parser::iter_lines_with(lines, Parser::parse_value::<usize>)
+                .collect::<Result<Vec<_>, _>>()?;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_parse_input_file() {
+        let path = std::env::temp_dir().join(format!("puzzler_input_{}.txt", std::process::id()));
+        std::fs::write(&path, "1\n42\n").unwrap();
+
+        let mut owning = FilePuzzle::new(&path, false);
+        let owned_result = owning.parse_input_file();
+        let mut borrowing = FilePuzzle::new(&path, true);
+        let borrowed_result = borrowing.parse_input_file();
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(owned_result.is_ok(), "{owned_result:?}");
+        assert_eq!(owning.lines, vec!["1", "42"]);
+        assert!(owning.numbers.is_empty());
+
+        assert!(borrowed_result.is_ok(), "{borrowed_result:?}");
+        assert_eq!(borrowing.numbers, vec![1, 42]);
+        assert!(borrowing.lines.is_empty());
+    }
+
     #[test]
     #[should_panic]
     fn test_new_too_little_parts() {
@@ -0,0 +1,302 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, HashSet},
+    hash::Hash,
+};
+
+/// Result of the shortest path search. Besides the cheapest cost it keeps all predecessors with
+/// the optimal cost, so that all optimal paths can be reconstructed.
+#[derive(Debug, Clone)]
+pub struct ShortestPaths<S> {
+    ids: HashMap<S, usize>,
+    states: Vec<S>,
+    costs: Vec<usize>,
+    predecessors: Vec<Vec<usize>>,
+    goals: Vec<usize>,
+}
+
+impl<S: Clone + Eq + Hash> ShortestPaths<S> {
+    /// Cost of the cheapest path to the goal, None if goal was not reached
+    pub fn cost(&self) -> Option<usize> {
+        self.goals.first().map(|goal| self.costs[*goal])
+    }
+
+    /// Cost of the cheapest path to the state, None if state was not reached
+    pub fn cost_to(&self, state: &S) -> Option<usize> {
+        self.ids.get(state).map(|id| self.costs[*id])
+    }
+
+    /// All goal states reached with the optimal cost
+    pub fn goals(&self) -> Vec<S> {
+        self.goals
+            .iter()
+            .map(|id| self.states[*id].clone())
+            .collect()
+    }
+
+    /// One of the optimal paths to the goal, start and goal states are included
+    pub fn path(&self) -> Option<Vec<S>> {
+        let goal = self.goals.first()?;
+        Some(self.path_to(&self.states[*goal]).unwrap_or_default())
+    }
+
+    /// One of the optimal paths to the state, start and the state are included
+    pub fn path_to(&self, state: &S) -> Option<Vec<S>> {
+        let mut id = *self.ids.get(state)?;
+        let mut path = vec![self.states[id].clone()];
+
+        while let Some(previous) = self.predecessors[id].first() {
+            id = *previous;
+            path.push(self.states[id].clone());
+        }
+        path.reverse();
+
+        Some(path)
+    }
+
+    /// All optimal paths to all optimal goals
+    pub fn paths(&self) -> Vec<Vec<S>> {
+        self.goals
+            .iter()
+            .flat_map(|goal| self.paths_to(&self.states[*goal]))
+            .collect()
+    }
+
+    /// All optimal paths to the state, there may be exponentially many of them. Steps with zero
+    /// cost must not form cycles, otherwise there are infinitely many optimal paths.
+    pub fn paths_to(&self, state: &S) -> Vec<Vec<S>> {
+        let Some(id) = self.ids.get(state) else {
+            return Vec::new();
+        };
+
+        let mut paths = Vec::new();
+        self.collect_paths(*id, &mut vec![*id], &mut paths);
+        paths
+    }
+
+    /// All states which lie on at least one optimal path to any optimal goal
+    pub fn states_on_paths(&self) -> HashSet<S> {
+        let mut visited = HashSet::new();
+        let mut stack = self.goals.clone();
+
+        while let Some(id) = stack.pop() {
+            if visited.insert(id) {
+                stack.extend(self.predecessors[id].iter().copied());
+            }
+        }
+
+        visited
+            .into_iter()
+            .map(|id| self.states[id].clone())
+            .collect()
+    }
+
+    // Walk predecessors back to the start, 'path' is stored in reversed order
+    fn collect_paths(&self, id: usize, path: &mut Vec<usize>, paths: &mut Vec<Vec<S>>) {
+        if self.predecessors[id].is_empty() {
+            paths.push(
+                path.iter()
+                    .rev()
+                    .map(|id| self.states[*id].clone())
+                    .collect(),
+            );
+            return;
+        }
+
+        for previous in &self.predecessors[id] {
+            path.push(*previous);
+            self.collect_paths(*previous, path, paths);
+            path.pop();
+        }
+    }
+
+    fn add_state(&mut self, state: S, cost: usize, predecessor: Option<usize>) -> usize {
+        let id = self.states.len();
+
+        self.ids.insert(state.clone(), id);
+        self.states.push(state);
+        self.costs.push(cost);
+        self.predecessors.push(predecessor.into_iter().collect());
+        id
+    }
+}
+
+/// Shortest path search over user defined states, e.g. position with facing direction and number
+/// of steps taken. States are expanded with successor function returning (state, cost) pairs.
+pub struct Dijkstra {}
+
+impl Dijkstra {
+    /// Find the cheapest paths from any start state to the states for which is_goal returns true.
+    /// If is_goal never returns true all reachable states are explored.
+    pub fn search<S, N, I, G>(
+        starts: impl IntoIterator<Item = S>,
+        successors: N,
+        is_goal: G,
+    ) -> ShortestPaths<S>
+    where
+        S: Clone + Eq + Hash,
+        N: FnMut(&S) -> I,
+        I: IntoIterator<Item = (S, usize)>,
+        G: FnMut(&S) -> bool,
+    {
+        Self::astar(starts, successors, is_goal, |_| 0)
+    }
+
+    /// Same as search(), but states are expanded in order of cost + heuristic(state). Heuristic
+    /// must never overestimate the remaining cost and must be consistent, otherwise the found
+    /// paths may not be optimal.
+    pub fn astar<S, N, I, G, H>(
+        starts: impl IntoIterator<Item = S>,
+        mut successors: N,
+        mut is_goal: G,
+        mut heuristic: H,
+    ) -> ShortestPaths<S>
+    where
+        S: Clone + Eq + Hash,
+        N: FnMut(&S) -> I,
+        I: IntoIterator<Item = (S, usize)>,
+        G: FnMut(&S) -> bool,
+        H: FnMut(&S) -> usize,
+    {
+        let mut result = ShortestPaths {
+            ids: HashMap::new(),
+            states: Vec::new(),
+            costs: Vec::new(),
+            predecessors: Vec::new(),
+            goals: Vec::new(),
+        };
+        let mut queue = BinaryHeap::new();
+        let mut goal_cost = None;
+
+        for start in starts {
+            if result.ids.contains_key(&start) {
+                continue;
+            }
+
+            let id = result.add_state(start, 0, None);
+            queue.push(Reverse((heuristic(&result.states[id]), 0, id)));
+        }
+
+        while let Some(Reverse((estimate, cost, id))) = queue.pop() {
+            // All optimal paths to the goal are known once cheaper states are exhausted
+            if goal_cost.is_some_and(|goal_cost| estimate > goal_cost) {
+                break;
+            }
+
+            // State was already reached by cheaper path
+            if cost > result.costs[id] {
+                continue;
+            }
+
+            if is_goal(&result.states[id]) {
+                goal_cost = Some(cost);
+                result.goals.push(id);
+                continue;
+            }
+
+            for (next, step) in successors(&result.states[id]) {
+                let next_cost = cost + step;
+
+                match result.ids.get(&next) {
+                    Some(next_id) => {
+                        let next_id = *next_id;
+
+                        if next_cost < result.costs[next_id] {
+                            result.costs[next_id] = next_cost;
+                            result.predecessors[next_id] = vec![id];
+                            queue.push(Reverse((next_cost + heuristic(&next), next_cost, next_id)));
+                        } else if next_cost == result.costs[next_id]
+                            && !result.predecessors[next_id].contains(&id)
+                        {
+                            result.predecessors[next_id].push(id);
+                        }
+                    }
+                    None => {
+                        let estimate = next_cost + heuristic(&next);
+                        let next_id = result.add_state(next, next_cost, Some(id));
+                        queue.push(Reverse((estimate, next_cost, next_id)));
+                    }
+                }
+            }
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Weighted graph: a -1-> b -1-> d, a -2-> c -0-> d, d -5-> e, a -9-> e
+    fn successors(node: &char) -> Vec<(char, usize)> {
+        match node {
+            'a' => vec![('b', 1), ('c', 2), ('e', 9)],
+            'b' => vec![('d', 1)],
+            'c' => vec![('d', 0)],
+            'd' => vec![('e', 5)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn test_search() {
+        let result = Dijkstra::search(['a'], successors, |node| *node == 'd');
+
+        assert_eq!(result.cost(), Some(2));
+        assert_eq!(result.goals(), vec!['d']);
+        assert_eq!(result.cost_to(&'b'), Some(1));
+        assert_eq!(result.path(), Some(vec!['a', 'b', 'd']));
+    }
+
+    #[test]
+    fn test_search_all_paths() {
+        let result = Dijkstra::search(['a'], successors, |node| *node == 'e');
+
+        assert_eq!(result.cost(), Some(7));
+        assert_eq!(
+            result.paths(),
+            vec![vec!['a', 'b', 'd', 'e'], vec!['a', 'c', 'd', 'e']]
+        );
+        assert_eq!(
+            result.states_on_paths(),
+            HashSet::from(['a', 'b', 'c', 'd', 'e'])
+        );
+    }
+
+    #[test]
+    fn test_search_no_goal() {
+        let result = Dijkstra::search(['b'], successors, |node| *node == 'a');
+
+        assert_eq!(result.cost(), None);
+        assert_eq!(result.path(), None);
+        assert!(result.paths().is_empty());
+        assert_eq!(result.cost_to(&'e'), Some(6));
+        assert_eq!(result.path_to(&'e'), Some(vec!['b', 'd', 'e']));
+        assert_eq!(result.path_to(&'a'), None);
+    }
+
+    #[test]
+    fn test_search_multiple_starts_and_goals() {
+        let result = Dijkstra::search(['b', 'c'], successors, |node| *node == 'd');
+
+        assert_eq!(result.cost(), Some(0));
+        assert_eq!(result.paths(), vec![vec!['c', 'd']]);
+    }
+
+    #[test]
+    fn test_astar() {
+        // Walk on the number line, only steps +1 and +3 are possible
+        let target = 10;
+        let result = Dijkstra::astar(
+            [0_isize],
+            |n| [(n + 1, 1), (n + 3, 1)],
+            |n| *n == target,
+            |n| ((target - n).max(0) as usize).div_ceil(3),
+        );
+
+        assert_eq!(result.cost(), Some(4));
+        assert_eq!(result.paths().len(), 4);
+        assert!(result.cost_to(&20).is_none());
+    }
+}
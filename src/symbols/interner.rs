@@ -0,0 +1,156 @@
+use std::{collections::HashMap, error::Error};
+
+/// Maximum number of letters which fit into u32 with encode_letters()
+pub const MAX_LETTERS: usize = 6;
+
+/// Maps strings (e.g. valve, wire or module names) to compact ids and back. Ids are assigned
+/// densely in order of interning, starting from 0.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Interner {
+    ids: HashMap<String, u32>,
+    names: Vec<String>,
+}
+
+impl Interner {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Get id of the name, name is added if it is not known yet
+    pub fn intern(&mut self, name: &str) -> u32 {
+        if let Some(id) = self.ids.get(name) {
+            return *id;
+        }
+
+        let id = self.names.len() as u32;
+        self.ids.insert(name.to_string(), id);
+        self.names.push(name.to_string());
+        id
+    }
+
+    /// Get id of already interned name
+    pub fn get(&self, name: &str) -> Option<u32> {
+        self.ids.get(name).copied()
+    }
+
+    /// Get name of the id
+    pub fn resolve(&self, id: u32) -> Option<&str> {
+        self.names.get(id as usize).map(String::as_str)
+    }
+
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    /// All interned names, index of the name is its id
+    pub fn names(&self) -> &[String] {
+        &self.names
+    }
+
+    /// Encode name of up to MAX_LETTERS letters to integer without any lookup. Letters are case
+    /// insensitive, e.g. "AA" => 0, "AB" => 1, "ZZ" => 675. Names of the same width map densely
+    /// to 0..letters_capacity(width).
+    pub fn encode_letters(name: &str) -> Result<u32, Box<dyn Error>> {
+        if name.is_empty() || name.len() > MAX_LETTERS {
+            return Err(format!(
+                "Name '{name}' must have 1 to {MAX_LETTERS} letters to be encoded"
+            )
+            .into());
+        }
+
+        name.bytes()
+            .try_fold(0, |acc, c| match c.is_ascii_alphabetic() {
+                true => Ok(acc * 26 + (c.to_ascii_lowercase() - b'a') as u32),
+                false => Err(format!("Name '{name}' contains non-letter '{}'", c as char).into()),
+            })
+    }
+
+    /// Decode value created by encode_letters() back to the name of the given width. Letters
+    /// start from 'first', i.e. 'a' for lowercase or 'A' for uppercase names.
+    pub fn decode_letters(value: u32, width: usize, first: char) -> String {
+        let mut letters = vec![first; width];
+        let mut value = value;
+
+        for letter in letters.iter_mut().rev() {
+            *letter = (first as u8 + (value % 26) as u8) as char;
+            value /= 26;
+        }
+
+        letters.into_iter().collect()
+    }
+
+    /// Number of distinct names of the given width, e.g. to size a lookup table
+    pub fn letters_capacity(width: usize) -> usize {
+        26_usize.pow(width as u32)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parsers::parser::Parser;
+
+    use super::*;
+
+    #[test]
+    fn test_intern() {
+        let mut interner = Interner::new();
+
+        assert_eq!(interner.intern("AA"), 0);
+        assert_eq!(interner.intern("BB"), 1);
+        assert_eq!(interner.intern("AA"), 0);
+        assert_eq!(interner.len(), 2);
+        assert_eq!(interner.get("BB"), Some(1));
+        assert_eq!(interner.get("CC"), None);
+        assert_eq!(interner.resolve(1), Some("BB"));
+        assert_eq!(interner.resolve(2), None);
+    }
+
+    #[test]
+    fn test_intern_with_regex() {
+        let lines = vec!["a -> b".to_string(), "b -> c".to_string()];
+        let mut interner = Interner::new();
+
+        let result = Parser::parse_lines_with_regex(lines, r"^(\w+) -> (\w+)$", |params| {
+            Ok((interner.intern(&params[0]), interner.intern(&params[1])))
+        });
+
+        assert!(result.is_ok(), "{:?}", result.err());
+        assert_eq!(result.unwrap(), vec![(0, 1), (1, 2)]);
+        assert_eq!(interner.names(), &["a", "b", "c"]);
+    }
+
+    #[test]
+    fn test_encode_letters() {
+        assert_eq!(Interner::encode_letters("AA").unwrap(), 0);
+        assert_eq!(Interner::encode_letters("AB").unwrap(), 1);
+        assert_eq!(Interner::encode_letters("ba").unwrap(), 26);
+        assert_eq!(Interner::encode_letters("ZZ").unwrap(), 675);
+        assert_eq!(Interner::encode_letters("zzz").unwrap(), 17575);
+        assert_eq!(Interner::encode_letters("zzzzzz").unwrap(), 308915775);
+    }
+
+    #[test]
+    fn test_encode_letters_error() {
+        assert!(Interner::encode_letters("").is_err());
+        assert!(Interner::encode_letters("a1").is_err());
+        assert!(Interner::encode_letters("abcdefg").is_err());
+    }
+
+    #[test]
+    fn test_decode_letters() {
+        assert_eq!(Interner::decode_letters(0, 2, 'A'), "AA");
+        assert_eq!(Interner::decode_letters(26, 2, 'a'), "ba");
+        assert_eq!(Interner::decode_letters(17575, 3, 'a'), "zzz");
+        assert_eq!(Interner::decode_letters(1, 3, 'A'), "AAB");
+    }
+
+    #[test]
+    fn test_letters_capacity() {
+        assert_eq!(Interner::letters_capacity(2), 676);
+        assert_eq!(Interner::letters_capacity(3), 17576);
+    }
+}
@@ -0,0 +1,294 @@
+use std::{collections::HashMap, error::Error};
+
+use crate::parsers::parser::Parser;
+
+/// Maximum number of operands of a single instruction
+pub const MAX_OPERANDS: usize = 3;
+
+/// Kind of the operand accepted by the instruction
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OperandKind {
+    Register,
+    Immediate,
+    Either,
+}
+
+/// Decoded operand, registers are referenced by index (see Program::registers)
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Operand {
+    #[default]
+    None,
+    Register(usize),
+    Immediate(isize),
+}
+
+/// Single decoded instruction. Opcode is user declared type, typically an enum.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Instruction<Op> {
+    pub opcode: Op,
+    operands: [Operand; MAX_OPERANDS],
+    count: usize,
+}
+
+impl<Op> Instruction<Op> {
+    pub fn new(opcode: Op, operands: &[Operand]) -> Self {
+        if operands.len() > MAX_OPERANDS {
+            panic!(
+                "Too many operands '{}', maximum is {MAX_OPERANDS}",
+                operands.len()
+            );
+        }
+
+        let mut all = [Operand::None; MAX_OPERANDS];
+        all[..operands.len()].copy_from_slice(operands);
+
+        Self {
+            opcode,
+            operands: all,
+            count: operands.len(),
+        }
+    }
+
+    pub fn operands(&self) -> &[Operand] {
+        &self.operands[..self.count]
+    }
+
+    /// Get operand at index, Operand::None is returned for missing operand
+    pub fn operand(&self, index: usize) -> Operand {
+        self.operands().get(index).copied().unwrap_or_default()
+    }
+
+    pub fn set_operand(&mut self, index: usize, operand: Operand) {
+        self.operands[..self.count][index] = operand;
+    }
+}
+
+/// Parsed program together with names of the used registers
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Program<Op> {
+    pub instructions: Vec<Instruction<Op>>,
+    pub registers: Vec<String>,
+}
+
+impl<Op> Program<Op> {
+    /// Get index of the register by its name
+    pub fn register(&self, name: &str) -> Option<usize> {
+        self.registers.iter().position(|register| register == name)
+    }
+}
+
+/// Declaration of the instructions of a toy CPU, e.g. "cpy a b" or "jnz x -2". Every mnemonic is
+/// mapped to user opcode and to the kinds of its operands.
+pub struct InstructionSet<Op> {
+    mnemonics: HashMap<String, (Op, Vec<OperandKind>)>,
+    registers: Vec<String>,
+}
+
+impl<Op: Copy> Default for InstructionSet<Op> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Op: Copy> InstructionSet<Op> {
+    pub fn new() -> Self {
+        Self {
+            mnemonics: HashMap::new(),
+            registers: Vec::new(),
+        }
+    }
+
+    /// Declare instruction with its operands
+    pub fn instruction(mut self, mnemonic: &str, opcode: Op, operands: &[OperandKind]) -> Self {
+        if operands.len() > MAX_OPERANDS {
+            panic!(
+                "Instruction '{mnemonic}' has too many operands '{}', maximum is {MAX_OPERANDS}",
+                operands.len()
+            );
+        }
+
+        self.mnemonics
+            .insert(mnemonic.to_string(), (opcode, operands.to_vec()));
+        self
+    }
+
+    /// Declare registers up front, so that their indexes do not depend on the program. Registers
+    /// which are not declared get the next free index in order of appearance.
+    pub fn registers(mut self, names: &[&str]) -> Self {
+        self.registers = names.iter().map(|name| name.to_string()).collect();
+        self
+    }
+
+    /// Parse every line to single instruction
+    pub fn parse(&self, lines: Vec<String>) -> Result<Program<Op>, Box<dyn Error>> {
+        let mut registers = self.registers.clone();
+
+        let instructions =
+            Parser::parse_lines_with(lines, |line| self.parse_line(line, &mut registers))?;
+
+        Ok(Program {
+            instructions,
+            registers,
+        })
+    }
+
+    fn parse_line(
+        &self,
+        line: &str,
+        registers: &mut Vec<String>,
+    ) -> Result<Instruction<Op>, Box<dyn Error>> {
+        let mut tokens = line
+            .split(|c: char| c.is_whitespace() || c == ',')
+            .filter(|token| !token.is_empty());
+
+        let mnemonic = tokens.next().ok_or("Missing instruction")?;
+        let (opcode, kinds) = self
+            .mnemonics
+            .get(mnemonic)
+            .ok_or_else(|| format!("Unknown instruction '{mnemonic}'"))?;
+
+        let tokens = tokens.collect::<Vec<_>>();
+        if tokens.len() != kinds.len() {
+            return Err(format!(
+                "Instruction '{mnemonic}' expects {} operand(s), found {}",
+                kinds.len(),
+                tokens.len()
+            )
+            .into());
+        }
+
+        let operands = tokens
+            .iter()
+            .zip(kinds)
+            .map(|(token, kind)| Self::parse_operand(token, kind, registers))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Instruction::new(*opcode, &operands))
+    }
+
+    fn parse_operand(
+        token: &str,
+        kind: &OperandKind,
+        registers: &mut Vec<String>,
+    ) -> Result<Operand, Box<dyn Error>> {
+        let is_register = token.starts_with(|c: char| c.is_alphabetic())
+            && token.chars().all(|c| c.is_alphanumeric() || c == '_');
+
+        match (kind, is_register) {
+            (OperandKind::Register, true) | (OperandKind::Either, true) => {
+                let index = match registers.iter().position(|register| register == token) {
+                    Some(index) => index,
+                    None => {
+                        registers.push(token.to_string());
+                        registers.len() - 1
+                    }
+                };
+
+                Ok(Operand::Register(index))
+            }
+            (OperandKind::Register, false) => {
+                Err(format!("Operand '{token}' is not a register").into())
+            }
+            (OperandKind::Immediate, true) => {
+                Err(format!("Operand '{token}' is not an immediate value").into())
+            }
+            (OperandKind::Immediate, false) | (OperandKind::Either, false) => {
+                Ok(Operand::Immediate(Parser::parse_value(token)?))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum Op {
+        Cpy,
+        Inc,
+        Jnz,
+    }
+
+    fn build_instruction_set() -> InstructionSet<Op> {
+        InstructionSet::new()
+            .instruction(
+                "cpy",
+                Op::Cpy,
+                &[OperandKind::Either, OperandKind::Register],
+            )
+            .instruction("inc", Op::Inc, &[OperandKind::Register])
+            .instruction(
+                "jnz",
+                Op::Jnz,
+                &[OperandKind::Either, OperandKind::Immediate],
+            )
+    }
+
+    #[test]
+    fn test_parse() {
+        let lines = vec![
+            "cpy 41 a".to_string(),
+            "inc b".to_string(),
+            "jnz a, -2".to_string(),
+            "cpy b a".to_string(),
+        ];
+
+        let result = build_instruction_set().parse(lines);
+        assert!(result.is_ok(), "{:?}", result.err());
+
+        let program = result.unwrap();
+        assert_eq!(program.registers, vec!["a".to_string(), "b".to_string()]);
+        assert_eq!(
+            program.instructions,
+            vec![
+                Instruction::new(Op::Cpy, &[Operand::Immediate(41), Operand::Register(0)]),
+                Instruction::new(Op::Inc, &[Operand::Register(1)]),
+                Instruction::new(Op::Jnz, &[Operand::Register(0), Operand::Immediate(-2)]),
+                Instruction::new(Op::Cpy, &[Operand::Register(1), Operand::Register(0)]),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_declared_registers() {
+        let lines = vec!["inc d".to_string(), "inc x".to_string()];
+
+        let program = build_instruction_set()
+            .registers(&["a", "b", "c", "d"])
+            .parse(lines)
+            .unwrap();
+
+        assert_eq!(program.register("d"), Some(3));
+        assert_eq!(program.register("x"), Some(4));
+        assert_eq!(
+            program.instructions[0],
+            Instruction::new(Op::Inc, &[Operand::Register(3)])
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let set = build_instruction_set();
+
+        assert!(set.parse(vec!["mul a b".to_string()]).is_err());
+        assert!(set.parse(vec!["inc 5".to_string()]).is_err());
+        assert!(set.parse(vec!["inc a b".to_string()]).is_err());
+        assert!(set.parse(vec!["jnz a b".to_string()]).is_err());
+
+        let err = set
+            .parse(vec!["inc a".to_string(), "cpy a".to_string()])
+            .err()
+            .unwrap();
+        assert!(err.to_string().starts_with("Line 2 'cpy a'"), "{err}");
+    }
+
+    #[test]
+    fn test_operand() {
+        let instruction = Instruction::new(Op::Inc, &[Operand::Register(1)]);
+
+        assert_eq!(instruction.operands(), &[Operand::Register(1)]);
+        assert_eq!(instruction.operand(0), Operand::Register(1));
+        assert_eq!(instruction.operand(1), Operand::None);
+    }
+}
@@ -0,0 +1,334 @@
+use std::{collections::VecDeque, error::Error};
+
+use crate::parsers::parser::Parser;
+
+/// State of the Intcode machine after run
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IntcodeState {
+    /// Input instruction found empty input queue, run can continue after input is provided
+    WaitingForInput,
+    /// Value was written to the output queue (only reported by run_until_output())
+    Output,
+    Halted,
+}
+
+/// Self-modifying integer machine with parameter modes and relative base. Input and output are
+/// queues, so that several machines can be chained (see pipe_to()). Machine can be cloned to take
+/// a snapshot of its state.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Intcode {
+    memory: Vec<isize>,
+    ip: usize,
+    relative_base: isize,
+    input: VecDeque<isize>,
+    output: VecDeque<isize>,
+    halted: bool,
+}
+
+impl Intcode {
+    pub fn new(program: Vec<isize>) -> Self {
+        Self {
+            memory: program,
+            ..Default::default()
+        }
+    }
+
+    /// Create machine from comma separated program, e.g. "1,9,10,3,2,3,11,0,99,30,40,50"
+    pub fn from_lines(lines: Vec<String>) -> Result<Self, Box<dyn Error>> {
+        let program = Parser::parse_lines_to_list(lines, &[','])?;
+
+        if program.is_empty() {
+            return Err("Intcode program is empty".into());
+        }
+
+        Ok(Self::new(program))
+    }
+
+    pub fn memory(&self) -> &[isize] {
+        &self.memory
+    }
+
+    /// Read value from memory, memory beyond the program is initialized to 0
+    pub fn read(&self, address: usize) -> isize {
+        self.memory.get(address).copied().unwrap_or(0)
+    }
+
+    /// Write value to memory, memory is extended if needed
+    pub fn write(&mut self, address: usize, value: isize) {
+        if address >= self.memory.len() {
+            self.memory.resize(address + 1, 0);
+        }
+        self.memory[address] = value;
+    }
+
+    pub fn is_halted(&self) -> bool {
+        self.halted
+    }
+
+    pub fn push_input(&mut self, value: isize) {
+        self.input.push_back(value);
+    }
+
+    pub fn input_mut(&mut self) -> &mut VecDeque<isize> {
+        &mut self.input
+    }
+
+    pub fn output(&self) -> &VecDeque<isize> {
+        &self.output
+    }
+
+    pub fn output_mut(&mut self) -> &mut VecDeque<isize> {
+        &mut self.output
+    }
+
+    pub fn pop_output(&mut self) -> Option<isize> {
+        self.output.pop_front()
+    }
+
+    /// Take all values from the output queue
+    pub fn take_output(&mut self) -> Vec<isize> {
+        self.output.drain(..).collect()
+    }
+
+    /// Move all values from the output queue to the input queue of other machine
+    pub fn pipe_to(&mut self, other: &mut Intcode) {
+        other.input.extend(self.output.drain(..));
+    }
+
+    /// Run until the machine halts or waits for input
+    pub fn run(&mut self) -> Result<IntcodeState, Box<dyn Error>> {
+        self.execute(false)
+    }
+
+    /// Run until the machine produces a single output, halts or waits for input
+    pub fn run_until_output(&mut self) -> Result<IntcodeState, Box<dyn Error>> {
+        self.execute(true)
+    }
+
+    fn execute(&mut self, stop_on_output: bool) -> Result<IntcodeState, Box<dyn Error>> {
+        while !self.halted {
+            let instruction = self.read(self.ip);
+            let opcode = instruction % 100;
+
+            match opcode {
+                // add, multiply, less than, equals
+                1 | 2 | 7 | 8 => {
+                    let a = self.param(instruction, 1)?;
+                    let b = self.param(instruction, 2)?;
+                    let value = match opcode {
+                        1 => a + b,
+                        2 => a * b,
+                        7 => (a < b) as isize,
+                        _ => (a == b) as isize,
+                    };
+
+                    let address = self.address(instruction, 3)?;
+                    self.write(address, value);
+                    self.ip += 4;
+                }
+                // input
+                3 => {
+                    let Some(value) = self.input.pop_front() else {
+                        return Ok(IntcodeState::WaitingForInput);
+                    };
+
+                    let address = self.address(instruction, 1)?;
+                    self.write(address, value);
+                    self.ip += 2;
+                }
+                // output
+                4 => {
+                    let value = self.param(instruction, 1)?;
+                    self.output.push_back(value);
+                    self.ip += 2;
+
+                    if stop_on_output {
+                        return Ok(IntcodeState::Output);
+                    }
+                }
+                // jump if true, jump if false
+                5 | 6 => {
+                    let condition = self.param(instruction, 1)? != 0;
+
+                    match condition == (opcode == 5) {
+                        true => self.ip = Self::to_address(self.param(instruction, 2)?)?,
+                        false => self.ip += 3,
+                    }
+                }
+                // adjust relative base
+                9 => {
+                    self.relative_base += self.param(instruction, 1)?;
+                    self.ip += 2;
+                }
+                99 => self.halted = true,
+                _ => {
+                    return Err(
+                        format!("Invalid opcode '{instruction}' at address {}", self.ip).into(),
+                    );
+                }
+            }
+        }
+
+        Ok(IntcodeState::Halted)
+    }
+
+    // Mode of the parameter at offset (1-based) of the instruction
+    fn mode(instruction: isize, offset: usize) -> isize {
+        instruction / 10_isize.pow(offset as u32 + 1) % 10
+    }
+
+    // Value of the parameter at offset with respect to its mode
+    fn param(&self, instruction: isize, offset: usize) -> Result<isize, Box<dyn Error>> {
+        match Self::mode(instruction, offset) {
+            1 => Ok(self.read(self.ip + offset)),
+            _ => Ok(self.read(self.address(instruction, offset)?)),
+        }
+    }
+
+    // Address pointed to by the parameter at offset with respect to its mode
+    fn address(&self, instruction: isize, offset: usize) -> Result<usize, Box<dyn Error>> {
+        let raw = self.read(self.ip + offset);
+
+        match Self::mode(instruction, offset) {
+            0 => Self::to_address(raw),
+            2 => Self::to_address(self.relative_base + raw),
+            mode => Err(format!(
+                "Invalid parameter mode '{mode}' of instruction '{instruction}' at address {}",
+                self.ip
+            )
+            .into()),
+        }
+    }
+
+    fn to_address(value: isize) -> Result<usize, Box<dyn Error>> {
+        usize::try_from(value).map_err(|_| format!("Invalid address '{value}'").into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build_intcode(program: &str) -> Intcode {
+        Intcode::from_lines(vec![program.to_string()]).unwrap()
+    }
+
+    #[test]
+    fn test_from_lines() {
+        let intcode = build_intcode("1,0,0,3,99");
+        assert_eq!(intcode.memory(), &[1, 0, 0, 3, 99]);
+
+        assert!(Intcode::from_lines(vec!["".to_string()]).is_err());
+    }
+
+    #[test]
+    fn test_run_add_multiply() {
+        let mut intcode = build_intcode("1,9,10,3,2,3,11,0,99,30,40,50");
+
+        assert_eq!(intcode.run().unwrap(), IntcodeState::Halted);
+        assert_eq!(intcode.read(0), 3500);
+        assert!(intcode.is_halted());
+    }
+
+    #[test]
+    fn test_run_modes() {
+        let mut intcode = build_intcode("1002,4,3,4,33");
+
+        assert_eq!(intcode.run().unwrap(), IntcodeState::Halted);
+        assert_eq!(intcode.read(4), 99);
+    }
+
+    #[test]
+    fn test_run_compare_and_jump() {
+        // Output 999 if input is below 8, 1000 if it is equal to 8 and 1001 if it is above 8
+        let program = "3,21,1008,21,8,20,1005,20,22,107,8,21,20,1006,20,31,1106,0,36,98,0,0,\
+                       1002,21,125,20,4,20,1105,1,46,104,999,1105,1,46,1101,1000,1,20,4,20,\
+                       1105,1,46,98,99";
+
+        for (input, expected) in [(7, 999), (8, 1000), (9, 1001)] {
+            let mut intcode = build_intcode(program);
+            intcode.push_input(input);
+
+            assert_eq!(intcode.run().unwrap(), IntcodeState::Halted);
+            assert_eq!(intcode.take_output(), vec![expected]);
+        }
+    }
+
+    #[test]
+    fn test_run_relative_base() {
+        let program = "109,1,204,-1,1001,100,1,100,1008,100,16,101,1006,101,0,99";
+        let mut intcode = build_intcode(program);
+
+        assert_eq!(intcode.run().unwrap(), IntcodeState::Halted);
+        assert_eq!(intcode.take_output(), build_intcode(program).memory());
+    }
+
+    #[test]
+    fn test_run_large_numbers() {
+        let mut intcode = build_intcode("104,1125899906842624,99");
+
+        intcode.run().unwrap();
+        assert_eq!(intcode.pop_output(), Some(1125899906842624));
+    }
+
+    #[test]
+    fn test_run_waiting_for_input() {
+        let mut intcode = build_intcode("3,0,4,0,99");
+
+        assert_eq!(intcode.run().unwrap(), IntcodeState::WaitingForInput);
+
+        intcode.push_input(42);
+        assert_eq!(intcode.run().unwrap(), IntcodeState::Halted);
+        assert_eq!(intcode.take_output(), vec![42]);
+    }
+
+    #[test]
+    fn test_run_until_output() {
+        let mut intcode = build_intcode("104,1,104,2,99");
+
+        assert_eq!(intcode.run_until_output().unwrap(), IntcodeState::Output);
+        assert_eq!(intcode.pop_output(), Some(1));
+        assert_eq!(intcode.run_until_output().unwrap(), IntcodeState::Output);
+        assert_eq!(intcode.pop_output(), Some(2));
+        assert_eq!(intcode.run_until_output().unwrap(), IntcodeState::Halted);
+    }
+
+    #[test]
+    fn test_pipe_to() {
+        // Read input, increment it and write it to output
+        let program = "3,0,1001,0,1,0,4,0,99";
+        let mut first = build_intcode(program);
+        let mut second = build_intcode(program);
+
+        first.push_input(1);
+        first.run().unwrap();
+        first.pipe_to(&mut second);
+        second.run().unwrap();
+
+        assert!(first.output().is_empty());
+        assert_eq!(second.take_output(), vec![3]);
+    }
+
+    #[test]
+    fn test_snapshot() {
+        let mut intcode = build_intcode("3,0,4,0,99");
+        intcode.run().unwrap();
+
+        let mut snapshot = intcode.clone();
+
+        intcode.push_input(1);
+        intcode.run().unwrap();
+        snapshot.push_input(2);
+        snapshot.run().unwrap();
+
+        assert_eq!(intcode.take_output(), vec![1]);
+        assert_eq!(snapshot.take_output(), vec![2]);
+    }
+
+    #[test]
+    fn test_run_invalid_opcode() {
+        let mut intcode = build_intcode("42,0,0,0,99");
+
+        assert!(intcode.run().is_err());
+    }
+}
@@ -0,0 +1,330 @@
+use std::{collections::HashSet, error::Error};
+
+use super::instruction::{Instruction, Operand, Program};
+
+/// Control flow requested by the executed instruction
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Flow {
+    /// Continue with the next instruction
+    Next,
+    /// Jump relatively to the current instruction
+    Jump(isize),
+    /// Stop the machine
+    Halt,
+}
+
+/// State of the machine after step or run
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum State {
+    Running,
+    Halted,
+    /// Execution reached breakpoint at given program counter, instruction is not executed yet
+    Breakpoint(usize),
+    /// Condition of run_until() was met
+    Stopped,
+}
+
+/// Register machine interpreting parsed Program. Semantics of the instructions is provided by
+/// the user function, which gets the machine and the instruction to execute and returns Flow.
+#[derive(Debug, Clone)]
+pub struct Machine<Op> {
+    program: Program<Op>,
+    registers: Vec<isize>,
+    pc: isize,
+    halted: bool,
+    steps: usize,
+    breakpoints: HashSet<usize>,
+    trace: Option<Vec<usize>>,
+}
+
+impl<Op: Copy> Machine<Op> {
+    pub fn new(program: Program<Op>) -> Self {
+        let registers = vec![0; program.registers.len()];
+
+        Self {
+            program,
+            registers,
+            pc: 0,
+            halted: false,
+            steps: 0,
+            breakpoints: HashSet::new(),
+            trace: None,
+        }
+    }
+
+    pub fn program(&self) -> &Program<Op> {
+        &self.program
+    }
+
+    /// Access to the program, e.g. for self-modifying instructions
+    pub fn program_mut(&mut self) -> &mut Program<Op> {
+        &mut self.program
+    }
+
+    pub fn registers(&self) -> &[isize] {
+        &self.registers
+    }
+
+    pub fn register(&self, name: &str) -> Option<isize> {
+        self.program
+            .register(name)
+            .map(|index| self.registers[index])
+    }
+
+    pub fn set_register(&mut self, name: &str, value: isize) -> Result<(), Box<dyn Error>> {
+        let index = self
+            .program
+            .register(name)
+            .ok_or_else(|| format!("Unknown register '{name}'"))?;
+
+        self.registers[index] = value;
+        Ok(())
+    }
+
+    /// Value of the operand, missing operand is evaluated as 0
+    pub fn read(&self, operand: &Operand) -> isize {
+        match operand {
+            Operand::None => 0,
+            Operand::Register(index) => self.registers[*index],
+            Operand::Immediate(value) => *value,
+        }
+    }
+
+    /// Store value to the register operand
+    pub fn write(&mut self, operand: &Operand, value: isize) -> Result<(), Box<dyn Error>> {
+        match operand {
+            Operand::Register(index) => {
+                self.registers[*index] = value;
+                Ok(())
+            }
+            _ => Err(format!("Cannot write to operand '{operand:?}'").into()),
+        }
+    }
+
+    /// Program counter, i.e. index of the next instruction
+    pub fn pc(&self) -> isize {
+        self.pc
+    }
+
+    pub fn set_pc(&mut self, pc: isize) {
+        self.pc = pc;
+    }
+
+    /// Number of executed instructions
+    pub fn steps(&self) -> usize {
+        self.steps
+    }
+
+    /// Machine is halted by instruction or when program counter leaves the program
+    pub fn is_halted(&self) -> bool {
+        self.halted || self.pc < 0 || self.pc as usize >= self.program.instructions.len()
+    }
+
+    pub fn add_breakpoint(&mut self, pc: usize) {
+        self.breakpoints.insert(pc);
+    }
+
+    pub fn remove_breakpoint(&mut self, pc: usize) {
+        self.breakpoints.remove(&pc);
+    }
+
+    /// Start recording program counters of the executed instructions
+    pub fn enable_trace(&mut self) {
+        self.trace.get_or_insert_with(Vec::new);
+    }
+
+    /// Recorded program counters, empty if trace is not enabled
+    pub fn trace(&self) -> &[usize] {
+        self.trace.as_deref().unwrap_or_default()
+    }
+
+    /// Execute single instruction
+    pub fn step<F>(&mut self, exec: &mut F) -> Result<State, Box<dyn Error>>
+    where
+        F: FnMut(&mut Self, Instruction<Op>) -> Result<Flow, Box<dyn Error>>,
+    {
+        if self.is_halted() {
+            return Ok(State::Halted);
+        }
+
+        let pc = self.pc as usize;
+        let instruction = self.program.instructions[pc];
+
+        if let Some(trace) = self.trace.as_mut() {
+            trace.push(pc);
+        }
+
+        let flow =
+            exec(self, instruction).map_err(|err| format!("Instruction at {pc} failed [{err}]"))?;
+        self.steps += 1;
+
+        match flow {
+            Flow::Next => self.pc += 1,
+            Flow::Jump(offset) => self.pc += offset,
+            Flow::Halt => self.halted = true,
+        }
+
+        match self.is_halted() {
+            true => Ok(State::Halted),
+            false => Ok(State::Running),
+        }
+    }
+
+    /// Run until the machine halts or reaches a breakpoint. Instruction at the current program
+    /// counter is always executed, so that run can continue from the breakpoint.
+    pub fn run<F>(&mut self, exec: F) -> Result<State, Box<dyn Error>>
+    where
+        F: FnMut(&mut Self, Instruction<Op>) -> Result<Flow, Box<dyn Error>>,
+    {
+        self.run_until(exec, |_| false)
+    }
+
+    /// Run until the machine halts, reaches a breakpoint or the condition checked after every
+    /// step is met.
+    pub fn run_until<F, P>(&mut self, mut exec: F, mut stop: P) -> Result<State, Box<dyn Error>>
+    where
+        F: FnMut(&mut Self, Instruction<Op>) -> Result<Flow, Box<dyn Error>>,
+        P: FnMut(&Self) -> bool,
+    {
+        let mut first = true;
+
+        loop {
+            if !first && !self.is_halted() && self.breakpoints.contains(&(self.pc as usize)) {
+                return Ok(State::Breakpoint(self.pc as usize));
+            }
+            first = false;
+
+            let state = self.step(&mut exec)?;
+
+            if state == State::Halted {
+                return Ok(state);
+            }
+
+            if stop(self) {
+                return Ok(State::Stopped);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::vm::instruction::{InstructionSet, OperandKind};
+
+    use super::*;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum Op {
+        Cpy,
+        Inc,
+        Dec,
+        Jnz,
+    }
+
+    fn build_machine(lines: &[&str]) -> Machine<Op> {
+        let program = InstructionSet::new()
+            .instruction(
+                "cpy",
+                Op::Cpy,
+                &[OperandKind::Either, OperandKind::Register],
+            )
+            .instruction("inc", Op::Inc, &[OperandKind::Register])
+            .instruction("dec", Op::Dec, &[OperandKind::Register])
+            .instruction("jnz", Op::Jnz, &[OperandKind::Either, OperandKind::Either])
+            .registers(&["a", "b", "c", "d"])
+            .parse(lines.iter().map(|line| line.to_string()).collect())
+            .unwrap();
+
+        Machine::new(program)
+    }
+
+    fn exec(
+        machine: &mut Machine<Op>,
+        instruction: Instruction<Op>,
+    ) -> Result<Flow, Box<dyn Error>> {
+        let x = instruction.operand(0);
+        let y = instruction.operand(1);
+
+        match instruction.opcode {
+            Op::Cpy => machine.write(&y, machine.read(&x))?,
+            Op::Inc => machine.write(&x, machine.read(&x) + 1)?,
+            Op::Dec => machine.write(&x, machine.read(&x) - 1)?,
+            Op::Jnz => {
+                if machine.read(&x) != 0 {
+                    return Ok(Flow::Jump(machine.read(&y)));
+                }
+            }
+        }
+
+        Ok(Flow::Next)
+    }
+
+    const PROGRAM: [&str; 6] = ["cpy 41 a", "inc a", "inc a", "dec a", "jnz a 2", "dec a"];
+
+    #[test]
+    fn test_run() {
+        let mut machine = build_machine(&PROGRAM);
+
+        let result = machine.run(exec);
+        assert_eq!(result.unwrap(), State::Halted);
+        assert_eq!(machine.register("a"), Some(42));
+        assert_eq!(machine.steps(), 5);
+    }
+
+    #[test]
+    fn test_step() {
+        let mut machine = build_machine(&PROGRAM);
+
+        assert_eq!(machine.step(&mut exec).unwrap(), State::Running);
+        assert_eq!(machine.register("a"), Some(41));
+        assert_eq!(machine.pc(), 1);
+    }
+
+    #[test]
+    fn test_loop() {
+        let mut machine = build_machine(&["cpy 3 b", "inc a", "dec b", "jnz b -2"]);
+
+        assert_eq!(machine.run(exec).unwrap(), State::Halted);
+        assert_eq!(machine.register("a"), Some(3));
+        assert_eq!(machine.register("b"), Some(0));
+    }
+
+    #[test]
+    fn test_breakpoint() {
+        let mut machine = build_machine(&PROGRAM);
+        machine.add_breakpoint(3);
+
+        assert_eq!(machine.run(exec).unwrap(), State::Breakpoint(3));
+        assert_eq!(machine.register("a"), Some(43));
+
+        // Continue from the breakpoint
+        assert_eq!(machine.run(exec).unwrap(), State::Halted);
+        assert_eq!(machine.register("a"), Some(42));
+    }
+
+    #[test]
+    fn test_run_until() {
+        let mut machine = build_machine(&PROGRAM);
+
+        let result = machine.run_until(exec, |machine| machine.register("a") == Some(43));
+        assert_eq!(result.unwrap(), State::Stopped);
+        assert_eq!(machine.pc(), 3);
+    }
+
+    #[test]
+    fn test_trace() {
+        let mut machine = build_machine(&PROGRAM);
+        machine.enable_trace();
+
+        machine.run(exec).unwrap();
+        assert_eq!(machine.trace(), &[0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_exec_error() {
+        let mut machine = build_machine(&["cpy 1 a", "inc a"]);
+
+        let result = machine.run(|_, _| Err("Oops".into()));
+        assert!(result.is_err());
+    }
+}
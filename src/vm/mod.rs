@@ -0,0 +1,3 @@
+pub mod instruction;
+pub mod intcode;
+pub mod machine;